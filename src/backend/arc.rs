@@ -0,0 +1,304 @@
+#![cfg(feature = "backends")]
+
+use super::{enumerate_from, Backend, ContiguousBackend};
+use crate::{DefaultSymbol, Symbol};
+use alloc::{collections::TryReserveError, sync::Arc, vec::Vec};
+use core::{
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    mem, slice,
+};
+
+/// An interner backend that stores every string in its own `Arc<str>`.
+///
+/// # Note
+///
+/// Strings interned via [`StringInterner::get_or_intern_arc`] are stored
+/// as-is by bumping their reference count instead of copying their contents.
+/// Strings interned by any other means are copied into a new `Arc<str>`.
+///
+/// [`StringInterner::get_or_intern_arc`]: crate::StringInterner::get_or_intern_arc
+///
+/// # Usage Hint
+///
+/// Use this backend if your strings are already reference counted, e.g.
+/// because they are shared across threads, and especially if they are large.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **ok**   |
+/// | Resolve     | **best** |
+/// | Allocations | **bad**  |
+/// | Footprint   | **ok**   |
+/// | Supports `get_or_intern_static` | **no** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **good** |
+#[derive(Debug)]
+pub struct ArcBackend<S = DefaultSymbol> {
+    strings: Vec<Arc<str>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S> PartialEq for ArcBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.strings == other.strings
+    }
+}
+
+impl<S> Eq for ArcBackend<S> where S: Symbol {}
+
+impl<S> Clone for ArcBackend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            strings: self.strings.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Default for ArcBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            strings: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> ArcBackend<S>
+where
+    S: Symbol,
+{
+    /// Pushes the given shared string and returns its symbol.
+    fn push_arc(&mut self, string: Arc<str>) -> S {
+        let symbol = S::from_usize(self.strings.len());
+        self.strings.push(string);
+        symbol
+    }
+
+    /// Returns the shared string for the given symbol if any.
+    ///
+    /// # Note
+    ///
+    /// Cloning the returned `Arc<str>` allows the string to outlive the backend.
+    #[inline]
+    pub fn resolve_arc(&self, symbol: S) -> Option<&Arc<str>> {
+        self.strings.get(symbol.to_usize())
+    }
+}
+
+impl<S> Backend for ArcBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a> = Iter<'a, S>
+    where
+        Self: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            strings: Vec::with_capacity(cap),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.push_arc(Arc::from(string))
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        S::try_from_usize(self.strings.len())?;
+        Some(self.intern(string))
+    }
+
+    #[inline]
+    fn intern_arc(&mut self, string: Arc<str>) -> Self::Symbol {
+        self.push_arc(string)
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.strings.try_reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.strings.shrink_to_fit();
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.strings.shrink_to(min_capacity);
+    }
+
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        self.total_string_bytes() + self.strings.capacity() * mem::size_of::<Arc<str>>()
+    }
+
+    fn clear(&mut self) {
+        self.strings.clear();
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.strings.truncate(len);
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.resolve_index(symbol.to_usize())
+    }
+
+    #[inline]
+    fn resolve_index(&self, index: usize) -> Option<&str> {
+        self.strings.get(index).map(|string| &**string)
+    }
+
+    #[inline]
+    fn contains_symbol(&self, symbol: Self::Symbol) -> bool {
+        symbol.to_usize() < self.strings.len()
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.strings.get_unchecked(symbol.to_usize()) }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+
+    #[inline]
+    fn iter_from(&self, start: usize) -> Self::Iter<'_> {
+        Iter::new_from(self, start)
+    }
+}
+
+impl<S> ContiguousBackend for ArcBackend<S> where S: Symbol {}
+
+impl<'a, S> IntoIterator for &'a ArcBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    iter: Enumerate<slice::Iter<'a, Arc<str>>>,
+    symbol_marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a ArcBackend<S>) -> Self {
+        Self::new_from(backend, 0)
+    }
+
+    /// Creates an iterator starting at the string with index `start`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new_from(backend: &'a ArcBackend<S>, start: usize) -> Self {
+        Self {
+            iter: enumerate_from(&backend.strings, start),
+            symbol_marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(id, string)| (S::from_usize(id), &**string))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(id, string)| (S::from_usize(id), &**string))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::{String, ToString};
+
+    #[test]
+    fn intern_arc_shares_storage() {
+        let mut backend = <ArcBackend>::default();
+        let shared = Arc::<str>::from("Tiger");
+        let tiger = backend.intern_arc(Arc::clone(&shared));
+        let horse = backend.intern_owned(String::from("Horse"));
+        assert_eq!(Arc::strong_count(&shared), 2);
+        assert!(Arc::ptr_eq(backend.resolve_arc(tiger).unwrap(), &shared));
+        assert_eq!(backend.resolve(horse), Some("Horse"));
+        backend.clear();
+        assert_eq!(Arc::strong_count(&shared), 1);
+    }
+
+    #[test]
+    fn addresses_are_stable() {
+        let mut backend = <ArcBackend>::with_capacity(1);
+        let tiger = backend.intern("Tiger");
+        let ptr = backend.resolve(tiger).unwrap().as_ptr();
+        for i in 0..1_000 {
+            backend.intern(&i.to_string());
+        }
+        assert_eq!(backend.resolve(tiger).unwrap().as_ptr(), ptr);
+    }
+}
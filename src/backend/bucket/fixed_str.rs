@@ -36,6 +36,12 @@ impl FixedString {
         self.contents.len()
     }
 
+    /// Removes all contents from the fixed string while keeping its capacity.
+    #[inline]
+    pub fn clear(&mut self) {
+        self.contents.clear()
+    }
+
     /// Pushes the given string into the fixed string if there is enough capacity.
     ///
     /// Returns a reference to the pushed string if there was enough capacity to
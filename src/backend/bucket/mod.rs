@@ -4,12 +4,16 @@ mod fixed_str;
 mod interned_str;
 
 use self::{fixed_str::FixedString, interned_str::InternedStr};
-use super::Backend;
-use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
+use super::{enumerate_from, Backend, ContiguousBackend};
+use crate::{DefaultSymbol, Symbol};
 #[cfg(not(feature = "std"))]
 use alloc::string::String;
-use alloc::vec::Vec;
-use core::{iter::Enumerate, marker::PhantomData, slice};
+use alloc::{collections::TryReserveError, vec::Vec};
+use core::{
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    mem, slice,
+};
 
 /// An interner backend that reduces memory allocations by using string buckets.
 ///
@@ -97,6 +101,16 @@ where
         }
     }
 
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity_for(cap: usize, bytes: usize) -> Self {
+        Self {
+            spans: Vec::with_capacity(cap),
+            head: FixedString::with_capacity(bytes),
+            full: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+
     #[inline]
     fn intern(&mut self, string: &str) -> Self::Symbol {
         // SAFETY: This is safe because we never hand out the returned
@@ -106,12 +120,29 @@ where
         self.push_span(interned)
     }
 
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        S::try_from_usize(self.spans.len())?;
+        Some(self.intern(string))
+    }
+
     #[cfg_attr(feature = "inline-more", inline)]
     fn intern_static(&mut self, string: &'static str) -> Self::Symbol {
         let interned = InternedStr::new(string);
         self.push_span(interned)
     }
 
+    fn intern_owned(&mut self, string: String) -> Self::Symbol {
+        // Moving the string into `full` does not move its heap allocation.
+        let interned = InternedStr::new(&string);
+        self.full.push(string);
+        self.push_span(interned)
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.spans.try_reserve(additional)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.spans.shrink_to_fit();
         // Commenting out the below line fixes: https://github.com/Robbepop/string-interner/issues/46
@@ -119,9 +150,51 @@ where
         self.full.shrink_to_fit();
     }
 
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.spans.shrink_to(min_capacity);
+        self.full.shrink_to_fit();
+    }
+
+    #[inline]
+    fn byte_capacity(&self) -> usize {
+        self.head.capacity() + self.full.iter().map(String::len).sum::<usize>()
+    }
+
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        self.spans.capacity() * mem::size_of::<InternedStr>()
+            + self.head.capacity()
+            + self.full.capacity() * mem::size_of::<String>()
+            + self.full.iter().map(String::capacity).sum::<usize>()
+    }
+
+    fn clear(&mut self) {
+        // The spans must be cleared before the strings they are referring to.
+        self.spans.clear();
+        self.head.clear();
+        self.full.clear();
+    }
+
+    /// # Note
+    ///
+    /// The storage of the removed strings is not reclaimed until the backend is cleared.
+    fn truncate(&mut self, len: usize) {
+        self.spans.truncate(len);
+    }
+
     #[inline]
     fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
-        self.spans.get(symbol.to_usize()).map(InternedStr::as_str)
+        self.resolve_index(symbol.to_usize())
+    }
+
+    #[inline]
+    fn resolve_index(&self, index: usize) -> Option<&str> {
+        self.spans.get(index).map(InternedStr::as_str)
+    }
+
+    #[inline]
+    fn contains_symbol(&self, symbol: Self::Symbol) -> bool {
+        symbol.to_usize() < self.spans.len()
     }
 
     #[inline]
@@ -135,15 +208,22 @@ where
     fn iter(&self) -> Self::Iter<'_> {
         Iter::new(self)
     }
+
+    #[inline]
+    fn iter_from(&self, start: usize) -> Self::Iter<'_> {
+        Iter::new_from(self, start)
+    }
 }
 
+impl<S> ContiguousBackend for BucketBackend<S> where S: Symbol {}
+
 impl<S> BucketBackend<S>
 where
     S: Symbol,
 {
     /// Returns the next available symbol.
     fn next_symbol(&self) -> S {
-        expect_valid_symbol(self.spans.len())
+        S::from_usize(self.spans.len())
     }
 
     /// Pushes the given interned string into the spans and returns its symbol.
@@ -225,8 +305,14 @@ pub struct Iter<'a, S> {
 impl<'a, S> Iter<'a, S> {
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn new(backend: &'a BucketBackend<S>) -> Self {
+        Self::new_from(backend, 0)
+    }
+
+    /// Creates an iterator starting at the string with index `start`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new_from(backend: &'a BucketBackend<S>, start: usize) -> Self {
         Self {
-            iter: backend.spans.iter().enumerate(),
+            iter: enumerate_from(&backend.spans, start),
             symbol_marker: Default::default(),
         }
     }
@@ -247,6 +333,94 @@ where
     fn next(&mut self) -> Option<Self::Item> {
         self.iter
             .next()
-            .map(|(id, interned)| (expect_valid_symbol(id), interned.as_str()))
+            .map(|(id, interned)| (S::from_usize(id), interned.as_str()))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(id, interned)| (S::from_usize(id), interned.as_str()))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::string::ToString;
+
+    #[test]
+    fn intern_static_does_not_copy() {
+        let mut backend = <BucketBackend>::default();
+        let string: &'static str = "static";
+        let symbol = backend.intern_static(string);
+        let resolved = backend.resolve(symbol).unwrap();
+        assert_eq!(resolved, string);
+        assert_eq!(resolved.as_ptr(), string.as_ptr());
+        assert_eq!(backend.head.len(), 0);
+        assert!(backend.full.is_empty());
+    }
+
+    #[test]
+    fn resolve_across_bucket_growth_works() {
+        // The interned strings point into buckets that are moved into `full`
+        // as the backend grows. Run under Miri to check the references stay valid.
+        let mut backend = <BucketBackend>::with_capacity(1);
+        let mut symbols = Vec::new();
+        for string in ["a", "bb", "ccc", "dddd", "eeeee", "ffffff"] {
+            symbols.push((backend.intern(string), string));
+            for &(symbol, string) in &symbols {
+                assert_eq!(backend.resolve(symbol), Some(string));
+            }
+        }
+        assert!(!backend.full.is_empty());
+        let cloned = backend.clone();
+        backend.clear();
+        for &(symbol, string) in &symbols {
+            assert_eq!(cloned.resolve(symbol), Some(string));
+        }
+    }
+
+    #[test]
+    fn addresses_are_stable() {
+        let mut backend = <BucketBackend>::with_capacity(1);
+        let tiger = backend.intern("Tiger");
+        let ptr = backend.resolve(tiger).unwrap().as_ptr();
+        for i in 0..1_000 {
+            backend.intern(&i.to_string());
+        }
+        assert!(!backend.full.is_empty());
+        assert_eq!(backend.resolve(tiger).unwrap().as_ptr(), ptr);
+    }
+
+    #[test]
+    fn intern_owned_does_not_copy() {
+        let mut backend = <BucketBackend>::default();
+        let string = String::from("owned");
+        let ptr = string.as_ptr();
+        let symbol = backend.intern_owned(string);
+        let resolved = backend.resolve(symbol).unwrap();
+        assert_eq!(resolved, "owned");
+        assert_eq!(resolved.as_ptr(), ptr);
+        assert_eq!(backend.head.len(), 0);
+        let cloned = backend.clone();
+        assert_eq!(cloned.resolve(symbol), Some("owned"));
     }
 }
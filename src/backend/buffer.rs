@@ -1,9 +1,9 @@
 #![cfg(feature = "backends")]
 
 use super::Backend;
-use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
-use alloc::vec::Vec;
-use core::{marker::PhantomData, mem, str};
+use crate::{DefaultSymbol, Symbol};
+use alloc::{collections::TryReserveError, vec::Vec};
+use core::{iter::FusedIterator, marker::PhantomData, mem, str};
 
 /// An interner backend that appends all interned string information in a single buffer.
 ///
@@ -79,7 +79,7 @@ where
     /// Returns the next available symbol.
     #[inline]
     fn next_symbol(&self) -> S {
-        expect_valid_symbol(self.buffer.len())
+        S::from_usize(self.buffer.len())
     }
 
     /// Resolves the string for the given symbol if any.
@@ -147,6 +147,15 @@ where
     }
 }
 
+/// We encode the `usize` string length into the buffer as well.
+const LEN_USIZE: usize = mem::size_of::<usize>();
+
+/// According to google the approx. word length is 5.
+const DEFAULT_STR_LEN: usize = 5;
+
+/// The estimated number of bytes required to store a single string.
+const BYTES_PER_STRING: usize = DEFAULT_STR_LEN + LEN_USIZE;
+
 impl<S> Backend for BufferBackend<S>
 where
     S: Symbol,
@@ -158,14 +167,18 @@ where
 
     #[cfg_attr(feature = "inline-more", inline)]
     fn with_capacity(capacity: usize) -> Self {
-        /// We encode the `usize` string length into the buffer as well.
-        const LEN_USIZE: usize = mem::size_of::<usize>();
-        /// According to google the approx. word length is 5.
-        const DEFAULT_STR_LEN: usize = 5;
-        let bytes_per_string = DEFAULT_STR_LEN + LEN_USIZE;
         Self {
             len_strings: 0,
-            buffer: Vec::with_capacity(capacity * bytes_per_string),
+            buffer: Vec::with_capacity(capacity * BYTES_PER_STRING),
+            marker: Default::default(),
+        }
+    }
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity_for(cap: usize, bytes: usize) -> Self {
+        Self {
+            len_strings: 0,
+            buffer: Vec::with_capacity(bytes.saturating_add(cap.saturating_mul(LEN_USIZE))),
             marker: Default::default(),
         }
     }
@@ -175,6 +188,12 @@ where
         self.push_string(string)
     }
 
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        S::try_from_usize(self.buffer.len())?;
+        Some(self.intern(string))
+    }
+
     #[inline]
     fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
         match self.resolve_index_to_str(symbol.to_usize()) {
@@ -183,10 +202,57 @@ where
         }
     }
 
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buffer
+            .try_reserve(additional.saturating_mul(BYTES_PER_STRING))
+    }
+
+    fn try_reserve_bytes(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buffer.try_reserve(additional)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.buffer.shrink_to_fit();
     }
 
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.buffer
+            .shrink_to(min_capacity.saturating_mul(BYTES_PER_STRING));
+    }
+
+    /// # Note
+    ///
+    /// The returned capacity includes the bytes required to encode string lengths.
+    #[inline]
+    fn byte_capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    fn clear(&mut self) {
+        self.len_strings = 0;
+        self.buffer.clear();
+    }
+
+    fn truncate(&mut self, len: usize) {
+        if len >= self.len_strings {
+            return;
+        }
+        let mut end = 0;
+        for _ in 0..len {
+            let (_, next) = self
+                .resolve_index_to_str(end)
+                .expect("encountered invalid string in buffer");
+            end = next;
+        }
+        self.buffer.truncate(end);
+        self.len_strings = len;
+    }
+
     #[inline]
     unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
         // SAFETY: The function is marked unsafe so that the caller guarantees
@@ -501,3 +567,5 @@ where
         self.remaining
     }
 }
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
@@ -0,0 +1,372 @@
+#![cfg(feature = "backends")]
+
+use super::{enumerate_from, Backend, ContiguousBackend};
+use crate::{DefaultSymbol, Symbol};
+use alloc::{boxed::Box, collections::TryReserveError, string::String, vec::Vec};
+use core::{
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    mem, slice, str,
+};
+
+/// The maximum length in bytes of strings that are stored inline.
+const INLINE_CAP: usize = 15;
+
+/// An interner backend that stores short strings inline without heap allocations.
+///
+/// # Note
+///
+/// Strings of up to 15 bytes, e.g. most identifiers, are stored inline in a
+/// fixed-size array. Only longer strings are allocated on the heap individually.
+/// Resolving a symbol branches on whether its string is stored inline.
+///
+/// # Usage Hint
+///
+/// Use this backend if the majority of your strings is short, e.g. for
+/// identifier heavy workloads, and you want to avoid per-string allocations.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **good** |
+/// | Resolve     | **good** |
+/// | Allocations | **good** |
+/// | Footprint   | **ok**   |
+/// | Supports `get_or_intern_static` | **no** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **good** |
+#[derive(Debug)]
+pub struct InlineBackend<S = DefaultSymbol> {
+    strings: Vec<SmallStr>,
+    marker: PhantomData<fn() -> S>,
+}
+
+/// A string that is either stored inline or on the heap.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum SmallStr {
+    /// A string of up to [`INLINE_CAP`] bytes stored inline.
+    Inline { len: u8, bytes: [u8; INLINE_CAP] },
+    /// A longer string stored on the heap.
+    Heap(Box<str>),
+}
+
+impl SmallStr {
+    /// Creates a small string from `string` storing it inline if possible.
+    fn new(string: &str) -> Self {
+        match Self::inline(string) {
+            Some(inline) => inline,
+            None => Self::Heap(Box::from(string)),
+        }
+    }
+
+    /// Creates a small string from the owned `string` storing it inline if possible.
+    ///
+    /// Reuses the allocation of `string` if it is not stored inline.
+    fn from_owned(string: String) -> Self {
+        match Self::inline(&string) {
+            Some(inline) => inline,
+            None => Self::Heap(string.into_boxed_str()),
+        }
+    }
+
+    /// Returns the inline small string for `string` if it fits.
+    fn inline(string: &str) -> Option<Self> {
+        if string.len() > INLINE_CAP {
+            return None;
+        }
+        let mut bytes = [0; INLINE_CAP];
+        bytes[..string.len()].copy_from_slice(string.as_bytes());
+        Some(Self::Inline {
+            len: string.len() as u8,
+            bytes,
+        })
+    }
+
+    /// Returns the string contents.
+    #[inline]
+    fn as_str(&self) -> &str {
+        match self {
+            Self::Inline { len, bytes } => {
+                // SAFETY: Inline bytes are always copied from a valid `str`
+                //         of exactly `len` bytes.
+                unsafe { str::from_utf8_unchecked(bytes.get_unchecked(..usize::from(*len))) }
+            }
+            Self::Heap(string) => string,
+        }
+    }
+
+    /// Returns the number of heap allocated bytes.
+    #[inline]
+    fn heap_bytes(&self) -> usize {
+        match self {
+            Self::Inline { .. } => 0,
+            Self::Heap(string) => string.len(),
+        }
+    }
+}
+
+impl<S> PartialEq for InlineBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.strings.len() == other.strings.len()
+            && self
+                .strings
+                .iter()
+                .zip(&other.strings)
+                .all(|(lhs, rhs)| lhs.as_str() == rhs.as_str())
+    }
+}
+
+impl<S> Eq for InlineBackend<S> where S: Symbol {}
+
+impl<S> Clone for InlineBackend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            strings: self.strings.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Default for InlineBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            strings: Vec::new(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> InlineBackend<S>
+where
+    S: Symbol,
+{
+    /// Pushes the given small string and returns its symbol.
+    fn push(&mut self, string: SmallStr) -> S {
+        let symbol = S::from_usize(self.strings.len());
+        self.strings.push(string);
+        symbol
+    }
+}
+
+impl<S> Backend for InlineBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a> = Iter<'a, S>
+    where
+        Self: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            strings: Vec::with_capacity(cap),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> Self::Symbol {
+        self.push(SmallStr::new(string))
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        S::try_from_usize(self.strings.len())?;
+        Some(self.intern(string))
+    }
+
+    #[inline]
+    fn intern_owned(&mut self, string: String) -> Self::Symbol {
+        self.push(SmallStr::from_owned(string))
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.strings.try_reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.strings.shrink_to_fit();
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.strings.shrink_to(min_capacity);
+    }
+
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        let heap_bytes = self.strings.iter().map(SmallStr::heap_bytes).sum::<usize>();
+        heap_bytes + self.strings.capacity() * mem::size_of::<SmallStr>()
+    }
+
+    fn clear(&mut self) {
+        self.strings.clear();
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.strings.truncate(len);
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.resolve_index(symbol.to_usize())
+    }
+
+    #[inline]
+    fn resolve_index(&self, index: usize) -> Option<&str> {
+        self.strings.get(index).map(SmallStr::as_str)
+    }
+
+    #[inline]
+    fn contains_symbol(&self, symbol: Self::Symbol) -> bool {
+        symbol.to_usize() < self.strings.len()
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.strings.get_unchecked(symbol.to_usize()).as_str() }
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+
+    #[inline]
+    fn iter_from(&self, start: usize) -> Self::Iter<'_> {
+        Iter::new_from(self, start)
+    }
+}
+
+impl<S> ContiguousBackend for InlineBackend<S> where S: Symbol {}
+
+impl<'a, S> IntoIterator for &'a InlineBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    iter: Enumerate<slice::Iter<'a, SmallStr>>,
+    symbol_marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a InlineBackend<S>) -> Self {
+        Self::new_from(backend, 0)
+    }
+
+    /// Creates an iterator starting at the string with index `start`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new_from(backend: &'a InlineBackend<S>, start: usize) -> Self {
+        Self {
+            iter: enumerate_from(&backend.strings, start),
+            symbol_marker: Default::default(),
+        }
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(id, string)| (S::from_usize(id), string.as_str()))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(id, string)| (S::from_usize(id), string.as_str()))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn short_strings_are_inline() {
+        let mut backend = <InlineBackend>::default();
+        let empty = backend.intern("");
+        let short = backend.intern("fifteen_bytes__");
+        let long = backend.intern("sixteen_bytes___");
+        let owned = backend.intern_owned(String::from("an owned long string"));
+        assert!(matches!(
+            backend.strings[0],
+            SmallStr::Inline { len: 0, .. }
+        ));
+        assert!(matches!(
+            backend.strings[1],
+            SmallStr::Inline { len: 15, .. }
+        ));
+        assert!(matches!(backend.strings[2], SmallStr::Heap(_)));
+        assert_eq!(backend.resolve(empty), Some(""));
+        assert_eq!(backend.resolve(short), Some("fifteen_bytes__"));
+        assert_eq!(backend.resolve(long), Some("sixteen_bytes___"));
+        assert_eq!(backend.resolve(owned), Some("an owned long string"));
+        assert_eq!(backend.total_string_bytes(), 51);
+        assert_eq!(
+            backend.iter().map(|(_, string)| string).collect::<Vec<_>>(),
+            [
+                "",
+                "fifteen_bytes__",
+                "sixteen_bytes___",
+                "an owned long string"
+            ]
+        );
+    }
+}
@@ -3,14 +3,46 @@
 //! The backend is the method or strategy that handles the actual interning.
 //! There are trade-offs for the different kinds of backends. A user should
 //! find the backend that suits their use case best.
+//!
+//! The backends provided by this crate are:
+//!
+//! - [`StringBackend`]: Accumulates all strings into a single `String`. (default)
+//! - [`ArcBackend`]: Stores every string in its own `Arc<str>`.
+//! - [`BufferBackend`]: Appends all strings with their lengths into a single buffer.
+//! - [`BucketBackend`]: Packs strings into buckets that are never reallocated.
+//! - [`SubstringBackend`]: Shares storage between strings and their substrings.
+//! - [`InlineBackend`]: Stores short strings inline and only allocates longer ones.
+//!
+//! # Address Stability
+//!
+//! Only the [`BucketBackend`] and the [`ArcBackend`] never move the contents
+//! of interned strings when interning new strings. All other backends may
+//! move them when their storage grows, e.g. the [`StringBackend`] reallocates
+//! its single `String`. Safe code is not affected by this since resolved
+//! strings borrow their interner, but unsafe code that keeps raw pointers to
+//! resolved strings across interning must use one of the former backends.
+//!
+//! Custom storage strategies can be plugged into the
+//! [`StringInterner`](`crate::StringInterner`) by implementing the [`Backend`] trait.
 
+mod arc;
 mod bucket;
 mod buffer;
+mod inline;
 mod string;
+mod substring;
 
 #[cfg(feature = "backends")]
-pub use self::{bucket::BucketBackend, buffer::BufferBackend, string::StringBackend};
+pub use self::{
+    arc::ArcBackend, bucket::BucketBackend, buffer::BufferBackend, inline::InlineBackend,
+    string::StringBackend, substring::SubstringBackend,
+};
 use crate::Symbol;
+use alloc::{collections::TryReserveError, sync::Arc};
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
+#[cfg(feature = "backends")]
+use core::{iter::Enumerate, slice};
 
 /// The default backend recommended for general use.
 #[cfg(feature = "backends")]
@@ -35,14 +67,48 @@ pub trait Backend: Default {
     /// The capacity denotes how many strings are expected to be interned.
     fn with_capacity(cap: usize) -> Self;
 
+    /// Creates a new backend for `cap` strings with a total of `bytes` bytes.
+    ///
+    /// # Note
+    ///
+    /// The default implementation ignores `bytes` and forwards to
+    /// [`with_capacity`](`Backend::with_capacity`). Backends that store
+    /// string contents in a preallocated buffer should implement this method.
+    #[inline]
+    fn with_capacity_for(cap: usize, bytes: usize) -> Self {
+        let _ = bytes;
+        Self::with_capacity(cap)
+    }
+
     /// Interns the given string and returns its interned ref and symbol.
     ///
     /// # Note
     ///
     /// The backend must make sure that the returned symbol maps back to the
     /// original string in its [`resolve`](`Backend::resolve`) method.
+    /// The `usize` value of the returned symbol must be greater than the
+    /// values of all symbols returned before, see [`Symbol`](`crate::Symbol`).
     fn intern(&mut self, string: &str) -> Self::Symbol;
 
+    /// Tries to intern the given string and returns its symbol.
+    ///
+    /// Returns `None` without modifying the backend if it ran out of symbols.
+    ///
+    /// # Note
+    ///
+    /// The backend must make sure that the returned symbol maps back to the
+    /// original string in its [`resolve`](`Backend::resolve`) method.
+    ///
+    /// The default implementation forwards to [`intern`](`Backend::intern`)
+    /// and therefore panics instead of returning `None` if the backend ran out
+    /// of symbols. Backends that can detect exhaustion upfront, e.g. by checking
+    /// [`Symbol::try_from_usize`](`crate::Symbol::try_from_usize`) for the index
+    /// of the next string, should implement this method.
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        Some(self.intern(string))
+    }
+
     /// Interns the given static string and returns its interned ref and symbol.
     ///
     /// # Note
@@ -57,12 +123,166 @@ pub trait Backend: Default {
         self.intern(string)
     }
 
+    /// Interns the given owned string and returns its interned ref and symbol.
+    ///
+    /// # Note
+    ///
+    /// The backend must make sure that the returned symbol maps back to the
+    /// original string in its [`resolve`](`Backend::resolve`) method.
+    #[inline]
+    fn intern_owned(&mut self, string: String) -> Self::Symbol {
+        // The default implementation simply forwards to the normal [`intern`]
+        // implementation. Backends that can take over the allocation of the
+        // string should implement this method.
+        self.intern(&string)
+    }
+
+    /// Interns the given shared string and returns its symbol.
+    ///
+    /// # Note
+    ///
+    /// The backend must make sure that the returned symbol maps back to the
+    /// original string in its [`resolve`](`Backend::resolve`) method.
+    #[inline]
+    fn intern_arc(&mut self, string: Arc<str>) -> Self::Symbol {
+        // The default implementation simply forwards to the normal [`intern`]
+        // implementation. Backends that can store the shared string without
+        // copying it should implement this method.
+        self.intern(&string)
+    }
+
+    /// Tries to reserve capacity for at least `additional` more strings.
+    ///
+    /// # Note
+    ///
+    /// The default implementation does not reserve any capacity.
+    /// Backends that are able to reserve capacity should implement this method.
+    #[inline]
+    fn try_reserve(&mut self, _additional: usize) -> Result<(), TryReserveError> {
+        Ok(())
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes of string contents.
+    ///
+    /// # Note
+    ///
+    /// The default implementation does not reserve any capacity.
+    /// Backends that pack string contents into a contiguous buffer should
+    /// implement this method.
+    #[inline]
+    fn try_reserve_bytes(&mut self, _additional: usize) -> Result<(), TryReserveError> {
+        Ok(())
+    }
+
     /// Shrink backend capacity to fit interned symbols exactly.
     fn shrink_to_fit(&mut self);
 
+    /// Shrink backend capacity while keeping room for at least `min_capacity` strings.
+    ///
+    /// # Note
+    ///
+    /// The default implementation does not shrink any capacity.
+    /// Backends that are able to shrink their capacity should implement this method.
+    #[inline]
+    fn shrink_to(&mut self, _min_capacity: usize) {}
+
+    /// Returns the total number of bytes of all interned strings.
+    ///
+    /// # Note
+    ///
+    /// The default implementation sums up the lengths of all interned strings.
+    /// Backends that keep track of this should implement this method.
+    #[inline]
+    fn total_string_bytes(&self) -> usize {
+        self.iter().map(|(_, string)| string.len()).sum()
+    }
+
+    /// Returns the number of string bytes the backend can hold without reallocating.
+    ///
+    /// # Note
+    ///
+    /// The default implementation returns the
+    /// [`total_string_bytes`](`Backend::total_string_bytes`) of the backend.
+    /// Backends that preallocate storage for strings should implement this method.
+    #[inline]
+    fn byte_capacity(&self) -> usize {
+        self.total_string_bytes()
+    }
+
+    /// Returns the approximate number of heap allocated bytes of the backend.
+    ///
+    /// # Note
+    ///
+    /// The default implementation returns the
+    /// [`total_string_bytes`](`Backend::total_string_bytes`) of the backend.
+    /// Backends should implement this method to account for their capacity
+    /// and auxiliary data structures.
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        self.total_string_bytes()
+    }
+
+    /// Removes all interned strings from the backend.
+    ///
+    /// # Note
+    ///
+    /// The default implementation replaces the backend with a default constructed
+    /// one. Backends that are able to retain their allocated capacity should
+    /// implement this method.
+    #[inline]
+    fn clear(&mut self) {
+        *self = Self::default();
+    }
+
+    /// Removes all but the first `len` interned strings from the backend.
+    ///
+    /// Does nothing if the backend holds `len` or fewer strings.
+    ///
+    /// # Note
+    ///
+    /// The symbols of the removed strings may be assigned to strings interned afterwards.
+    /// The default implementation rebuilds the backend from the retained strings.
+    /// Backends that are able to truncate their storage should implement this method.
+    fn truncate(&mut self, len: usize) {
+        let strings = self
+            .iter()
+            .take(len)
+            .map(|(_, string)| String::from(string))
+            .collect::<Vec<_>>();
+        if strings.len() < len {
+            return;
+        }
+        self.clear();
+        for string in strings {
+            self.intern_owned(string);
+        }
+    }
+
     /// Resolves the given symbol to its original string contents.
     fn resolve(&self, symbol: Self::Symbol) -> Option<&str>;
 
+    /// Resolves the string of the symbol with the given `usize` value if any.
+    ///
+    /// # Note
+    ///
+    /// The default implementation converts `index` into a symbol and resolves it.
+    /// Backends that can look up strings by index directly should implement this method.
+    #[inline]
+    fn resolve_index(&self, index: usize) -> Option<&str> {
+        self.resolve(Self::Symbol::try_from_usize(index)?)
+    }
+
+    /// Returns `true` if the given symbol refers to an interned string.
+    ///
+    /// # Note
+    ///
+    /// The default implementation checks if the symbol can be resolved.
+    /// Backends that can check this more efficiently should implement this method.
+    #[inline]
+    fn contains_symbol(&self, symbol: Self::Symbol) -> bool {
+        self.resolve(symbol).is_some()
+    }
+
     /// Resolves the given symbol to its original string contents.
     ///
     /// # Safety
@@ -75,5 +295,62 @@ pub trait Backend: Default {
     unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str;
 
     /// Creates an iterator that yields all interned strings and their symbols.
+    ///
+    /// # Note
+    ///
+    /// The iterator must yield the strings in ascending order of the `usize`
+    /// values of their symbols, i.e. in the order in which they have been interned.
     fn iter(&self) -> Self::Iter<'_>;
+
+    /// Creates an iterator that yields the interned strings and their symbols
+    /// starting at the first symbol whose `usize` value is not less than `start`.
+    ///
+    /// # Note
+    ///
+    /// The default implementation iterates over all preceding strings to skip them.
+    /// Backends with contiguous symbols should implement this method to start
+    /// at the string with index `start` directly.
+    #[inline]
+    fn iter_from(&self, start: usize) -> Self::Iter<'_> {
+        let skip = self
+            .iter()
+            .take_while(|(symbol, _)| symbol.to_usize() < start)
+            .count();
+        let mut iter = self.iter();
+        if let Some(last_skipped) = skip.checked_sub(1) {
+            iter.nth(last_skipped);
+        }
+        iter
+    }
+}
+
+/// Marker trait for backends whose symbols have contiguous values.
+///
+/// The `n`-th interned string of such a backend is associated to the symbol
+/// with the `usize` value `n`. Therefore the `usize` values `0..n` are exactly
+/// the symbols of the first `n` interned strings.
+///
+/// All backends provided by this crate except for the [`BufferBackend`]
+/// implement this trait. The [`BufferBackend`] uses byte offsets as symbols.
+/// Therefore it does not support APIs that construct symbols from their values,
+/// e.g. [`StringInterner::symbols`](`crate::StringInterner::symbols`):
+///
+/// ```compile_fail
+/// # use string_interner::{backend::BufferBackend, StringInterner};
+/// let interner = StringInterner::<BufferBackend>::from_iter(["a", "b"]);
+/// let symbols = interner.symbols();
+/// ```
+pub trait ContiguousBackend: Backend {}
+
+/// Returns an enumerating iterator over `slice` that starts at index `start`.
+///
+/// The yielded indices are relative to the start of `slice`.
+#[cfg(feature = "backends")]
+#[inline]
+fn enumerate_from<T>(slice: &[T], start: usize) -> Enumerate<slice::Iter<'_, T>> {
+    let mut iter = slice.iter().enumerate();
+    if let Some(last_skipped) = start.checked_sub(1) {
+        iter.nth(last_skipped);
+    }
+    iter
 }
@@ -1,9 +1,13 @@
 #![cfg(feature = "backends")]
 
-use super::Backend;
-use crate::{symbol::expect_valid_symbol, DefaultSymbol, Symbol};
-use alloc::{string::String, vec::Vec};
-use core::{iter::Enumerate, marker::PhantomData, slice};
+use super::{enumerate_from, Backend, ContiguousBackend};
+use crate::{DefaultSymbol, Symbol};
+use alloc::{collections::TryReserveError, string::String, vec::Vec};
+use core::{
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    mem, slice,
+};
 
 /// An interner backend that accumulates all interned string contents into one string.
 ///
@@ -97,7 +101,7 @@ where
 {
     /// Returns the next available symbol.
     fn next_symbol(&self) -> S {
-        expect_valid_symbol(self.ends.len())
+        S::from_usize(self.ends.len())
     }
 
     /// Returns the string associated to the span.
@@ -113,7 +117,11 @@ where
 
     /// Returns the span for the given symbol if any.
     fn symbol_to_span(&self, symbol: S) -> Option<Span> {
-        let index = symbol.to_usize();
+        self.index_to_span(symbol.to_usize())
+    }
+
+    /// Returns the span for the symbol with the given `usize` value if any.
+    fn index_to_span(&self, index: usize) -> Option<Span> {
         self.ends.get(index).copied().map(|to| {
             let from = self.ends.get(index.wrapping_sub(1)).copied().unwrap_or(0);
             Span { from, to }
@@ -130,6 +138,33 @@ where
         Span { from, to }
     }
 
+    /// Returns the contents of all interned strings concatenated in symbol order.
+    ///
+    /// Use [`StringBackend::ends`] to split the contents into the interned strings.
+    #[inline]
+    pub fn as_str(&self) -> &str {
+        &self.buffer
+    }
+
+    /// Returns the end offsets of all interned strings into [`StringBackend::as_str`].
+    ///
+    /// The string of the symbol with the `usize` value `i` spans the bytes
+    /// from `ends[i - 1]`, or `0` for the first string, up to `ends[i]`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{backend::StringBackend, StringInterner};
+    /// let interner = <StringInterner<StringBackend>>::from_iter(["Tiger", "Horse"]);
+    /// let backend = interner.backend();
+    /// assert_eq!(backend.as_str(), "TigerHorse");
+    /// assert_eq!(backend.ends(), [5, 10]);
+    /// ```
+    #[inline]
+    pub fn ends(&self) -> &[usize] {
+        &self.ends
+    }
+
     /// Pushes the given string into the buffer and returns its span.
     ///
     /// # Panics
@@ -144,6 +179,9 @@ where
     }
 }
 
+/// According to google the approx. word length is 5.
+const DEFAULT_WORD_LEN: usize = 5;
+
 impl<S> Backend for StringBackend<S>
 where
     S: Symbol,
@@ -155,11 +193,18 @@ where
 
     #[cfg_attr(feature = "inline-more", inline)]
     fn with_capacity(cap: usize) -> Self {
-        // According to google the approx. word length is 5.
-        let default_word_len = 5;
         Self {
             ends: Vec::with_capacity(cap),
-            buffer: String::with_capacity(cap * default_word_len),
+            buffer: String::with_capacity(cap * DEFAULT_WORD_LEN),
+            marker: Default::default(),
+        }
+    }
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity_for(cap: usize, bytes: usize) -> Self {
+        Self {
+            ends: Vec::with_capacity(cap),
+            buffer: String::with_capacity(bytes),
             marker: Default::default(),
         }
     }
@@ -169,17 +214,74 @@ where
         self.push_string(string)
     }
 
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        S::try_from_usize(self.ends.len())?;
+        Some(self.intern(string))
+    }
+
     #[inline]
     fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
         self.symbol_to_span(symbol)
             .map(|span| self.span_to_str(span))
     }
 
+    #[inline]
+    fn resolve_index(&self, index: usize) -> Option<&str> {
+        self.index_to_span(index).map(|span| self.span_to_str(span))
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.ends.try_reserve(additional)?;
+        self.buffer
+            .try_reserve(additional.saturating_mul(DEFAULT_WORD_LEN))
+    }
+
+    fn try_reserve_bytes(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buffer.try_reserve(additional)
+    }
+
     fn shrink_to_fit(&mut self) {
         self.ends.shrink_to_fit();
         self.buffer.shrink_to_fit();
     }
 
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.ends.shrink_to(min_capacity);
+        self.buffer
+            .shrink_to(min_capacity.saturating_mul(DEFAULT_WORD_LEN));
+    }
+
+    #[inline]
+    fn total_string_bytes(&self) -> usize {
+        self.buffer.len()
+    }
+
+    #[inline]
+    fn byte_capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        self.buffer.capacity() + self.ends.capacity() * mem::size_of::<usize>()
+    }
+
+    fn clear(&mut self) {
+        self.ends.clear();
+        self.buffer.clear();
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.ends.truncate(len);
+        self.buffer.truncate(self.ends.last().copied().unwrap_or(0));
+    }
+
+    #[inline]
+    fn contains_symbol(&self, symbol: Self::Symbol) -> bool {
+        symbol.to_usize() < self.ends.len()
+    }
+
     #[inline]
     unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
         // SAFETY: The function is marked unsafe so that the caller guarantees
@@ -191,8 +293,15 @@ where
     fn iter(&self) -> Self::Iter<'_> {
         Iter::new(self)
     }
+
+    #[inline]
+    fn iter_from(&self, start: usize) -> Self::Iter<'_> {
+        Iter::new_from(self, start)
+    }
 }
 
+impl<S> ContiguousBackend for StringBackend<S> where S: Symbol {}
+
 impl<'a, S> IntoIterator for &'a StringBackend<S>
 where
     S: Symbol,
@@ -215,10 +324,21 @@ pub struct Iter<'a, S> {
 impl<'a, S> Iter<'a, S> {
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn new(backend: &'a StringBackend<S>) -> Self {
+        Self::new_from(backend, 0)
+    }
+
+    /// Creates an iterator starting at the string with index `start`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new_from(backend: &'a StringBackend<S>, start: usize) -> Self {
         Self {
             backend,
-            start: 0,
-            ends: backend.ends.iter().enumerate(),
+            // The string with index `start` begins where its predecessor ends.
+            start: backend
+                .ends
+                .get(start.wrapping_sub(1))
+                .copied()
+                .unwrap_or(0),
+            ends: enumerate_from(&backend.ends, start),
         }
     }
 }
@@ -239,9 +359,66 @@ where
         self.ends.next().map(|(id, &to)| {
             let from = core::mem::replace(&mut self.start, to);
             (
-                expect_valid_symbol(id),
+                S::from_usize(id),
+                self.backend.span_to_str(Span { from, to }),
+            )
+        })
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.ends.next_back().map(|(id, &to)| {
+            let from = self
+                .backend
+                .ends
+                .get(id.wrapping_sub(1))
+                .copied()
+                .unwrap_or(0);
+            (
+                S::from_usize(id),
                 self.backend.span_to_str(Span { from, to }),
             )
         })
     }
 }
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::StringInterner;
+    #[cfg(not(feature = "std"))]
+    use alloc::{format, vec::Vec};
+
+    #[test]
+    fn dedup_survives_buffer_reallocations() {
+        let mut interner = <StringInterner<StringBackend>>::with_capacity(1);
+        let strings = (0..1000).map(|i| format!("string-{i}")).collect::<Vec<_>>();
+        let symbols = strings
+            .iter()
+            .map(|string| interner.get_or_intern(string))
+            .collect::<Vec<_>>();
+        for (string, &symbol) in strings.iter().zip(&symbols) {
+            assert_eq!(interner.get(string), Some(symbol));
+            assert_eq!(interner.get_or_intern(string), symbol);
+            assert_eq!(interner.resolve(symbol), Some(string.as_str()));
+        }
+        assert_eq!(interner.len(), strings.len());
+    }
+}
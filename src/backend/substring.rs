@@ -0,0 +1,350 @@
+#![cfg(feature = "backends")]
+
+use super::{enumerate_from, Backend, ContiguousBackend};
+use crate::{DefaultSymbol, Symbol};
+use alloc::{collections::TryReserveError, string::String, vec::Vec};
+use core::{
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    mem, slice,
+};
+
+/// An interner backend that shares storage between strings and their substrings.
+///
+/// # Note
+///
+/// When interning a string that is already contained in the buffer of the
+/// backend, e.g. as part of a previously interned string, the new string
+/// refers to the existing contents instead of copying them.
+///
+/// # Performance
+///
+/// Detecting shared contents requires a linear search of the whole buffer for
+/// every newly interned string. Therefore filling the backend takes time that is
+/// quadratic in the total length of the interned strings. Only use this backend
+/// for small sets of strings that share lots of contents, e.g. `foo::bar` and
+/// `bar`, and never for untrusted input or large numbers of strings.
+///
+/// # Usage Hint
+///
+/// Use this backend if memory consumption is what matters most to you and
+/// your strings are frequently contained in each other.
+///
+/// # Usage
+///
+/// - **Fill:** Efficiency of filling an empty string interner.
+/// - **Resolve:** Efficiency of interned string look-up given a symbol.
+/// - **Allocations:** The number of allocations performed by the backend.
+/// - **Footprint:** The total heap memory consumed by the backend.
+/// - **Contiguous:** True if the returned symbols have contiguous values.
+/// - **Iteration:** Efficiency of iterating over the interned strings.
+///
+/// Rating varies between **bad**, **ok**, **good** and **best**.
+///
+/// | Scenario    |  Rating  |
+/// |:------------|:--------:|
+/// | Fill        | **bad**  |
+/// | Resolve     | **good** |
+/// | Allocations | **good** |
+/// | Footprint   | **best** |
+/// | Supports `get_or_intern_static` | **no** |
+/// | `Send` + `Sync` | **yes** |
+/// | Contiguous  | **yes**  |
+/// | Iteration   | **good** |
+#[derive(Debug)]
+pub struct SubstringBackend<S = DefaultSymbol> {
+    spans: Vec<Span>,
+    buffer: String,
+    marker: PhantomData<fn() -> S>,
+}
+
+/// Represents a `[from, to)` index into the `SubstringBackend` buffer.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+struct Span {
+    from: usize,
+    to: usize,
+}
+
+impl<S> PartialEq for SubstringBackend<S>
+where
+    S: Symbol,
+{
+    fn eq(&self, other: &Self) -> bool {
+        self.spans.len() == other.spans.len()
+            && self
+                .iter()
+                .zip(other)
+                .all(|((_, lhs), (_, rhs))| lhs == rhs)
+    }
+}
+
+impl<S> Eq for SubstringBackend<S> where S: Symbol {}
+
+impl<S> Clone for SubstringBackend<S> {
+    fn clone(&self) -> Self {
+        Self {
+            spans: self.spans.clone(),
+            buffer: self.buffer.clone(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> Default for SubstringBackend<S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self {
+            spans: Vec::default(),
+            buffer: String::default(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S> SubstringBackend<S>
+where
+    S: Symbol,
+{
+    /// Returns the string associated to the span.
+    fn span_to_str(&self, span: Span) -> &str {
+        // SAFETY: The spans are always constructed from char boundaries of the
+        //         buffer which only ever grows, so they stay valid utf8 ranges.
+        unsafe { self.buffer.get_unchecked(span.from..span.to) }
+    }
+
+    /// Returns the span of the given string, appending it to the buffer if
+    /// it is not yet contained in the buffer.
+    fn find_or_push(&mut self, string: &str) -> Span {
+        let from = match self.buffer.find(string) {
+            Some(from) => from,
+            None => {
+                let from = self.buffer.len();
+                self.buffer.push_str(string);
+                from
+            }
+        };
+        Span {
+            from,
+            to: from + string.len(),
+        }
+    }
+}
+
+impl<S> Backend for SubstringBackend<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Iter<'a> = Iter<'a, S>
+    where
+        Self: 'a;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity(cap: usize) -> Self {
+        Self {
+            spans: Vec::with_capacity(cap),
+            buffer: String::new(),
+            marker: Default::default(),
+        }
+    }
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn with_capacity_for(cap: usize, bytes: usize) -> Self {
+        Self {
+            spans: Vec::with_capacity(cap),
+            buffer: String::with_capacity(bytes),
+            marker: Default::default(),
+        }
+    }
+
+    #[inline]
+    fn intern(&mut self, string: &str) -> Self::Symbol {
+        let symbol = S::from_usize(self.spans.len());
+        let span = self.find_or_push(string);
+        self.spans.push(span);
+        symbol
+    }
+
+    #[inline]
+    fn try_intern(&mut self, string: &str) -> Option<Self::Symbol> {
+        S::try_from_usize(self.spans.len())?;
+        Some(self.intern(string))
+    }
+
+    fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.spans.try_reserve(additional)
+    }
+
+    fn try_reserve_bytes(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.buffer.try_reserve(additional)
+    }
+
+    fn shrink_to_fit(&mut self) {
+        self.spans.shrink_to_fit();
+        self.buffer.shrink_to_fit();
+    }
+
+    fn shrink_to(&mut self, min_capacity: usize) {
+        self.spans.shrink_to(min_capacity);
+        self.buffer.shrink_to_fit();
+    }
+
+    #[inline]
+    fn byte_capacity(&self) -> usize {
+        self.buffer.capacity()
+    }
+
+    #[inline]
+    fn memory_usage(&self) -> usize {
+        self.buffer.capacity() + self.spans.capacity() * mem::size_of::<Span>()
+    }
+
+    fn clear(&mut self) {
+        self.spans.clear();
+        self.buffer.clear();
+    }
+
+    fn truncate(&mut self, len: usize) {
+        self.spans.truncate(len);
+        let end = self.spans.iter().map(|span| span.to).max().unwrap_or(0);
+        self.buffer.truncate(end);
+    }
+
+    #[inline]
+    fn resolve(&self, symbol: Self::Symbol) -> Option<&str> {
+        self.resolve_index(symbol.to_usize())
+    }
+
+    #[inline]
+    fn resolve_index(&self, index: usize) -> Option<&str> {
+        self.spans.get(index).map(|&span| self.span_to_str(span))
+    }
+
+    #[inline]
+    fn contains_symbol(&self, symbol: Self::Symbol) -> bool {
+        symbol.to_usize() < self.spans.len()
+    }
+
+    #[inline]
+    unsafe fn resolve_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let span = unsafe { *self.spans.get_unchecked(symbol.to_usize()) };
+        self.span_to_str(span)
+    }
+
+    #[inline]
+    fn iter(&self) -> Self::Iter<'_> {
+        Iter::new(self)
+    }
+
+    #[inline]
+    fn iter_from(&self, start: usize) -> Self::Iter<'_> {
+        Iter::new_from(self, start)
+    }
+}
+
+impl<S> ContiguousBackend for SubstringBackend<S> where S: Symbol {}
+
+impl<'a, S> IntoIterator for &'a SubstringBackend<S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+pub struct Iter<'a, S> {
+    backend: &'a SubstringBackend<S>,
+    spans: Enumerate<slice::Iter<'a, Span>>,
+}
+
+impl<'a, S> Iter<'a, S> {
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new(backend: &'a SubstringBackend<S>) -> Self {
+        Self::new_from(backend, 0)
+    }
+
+    /// Creates an iterator starting at the string with index `start`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new_from(backend: &'a SubstringBackend<S>, start: usize) -> Self {
+        Self {
+            backend,
+            spans: enumerate_from(&backend.spans, start),
+        }
+    }
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.spans.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.spans
+            .next()
+            .map(|(id, &span)| (S::from_usize(id), self.backend.span_to_str(span)))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.spans
+            .next_back()
+            .map(|(id, &span)| (S::from_usize(id), self.backend.span_to_str(span)))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.spans.len()
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn substrings_share_storage() {
+        let mut backend = <SubstringBackend>::default();
+        let path = backend.intern("foo::bar");
+        let foo = backend.intern("foo");
+        let bar = backend.intern("bar");
+        let baz = backend.intern("baz");
+        let empty = backend.intern("");
+        assert_eq!(backend.buffer, "foo::barbaz");
+        assert_eq!(backend.resolve(path), Some("foo::bar"));
+        assert_eq!(backend.resolve(foo), Some("foo"));
+        assert_eq!(backend.resolve(bar), Some("bar"));
+        assert_eq!(backend.resolve(baz), Some("baz"));
+        assert_eq!(backend.resolve(empty), Some(""));
+        assert_eq!(backend.total_string_bytes(), 17);
+        assert_eq!(
+            backend.iter().map(|(_, string)| string).collect::<Vec<_>>(),
+            ["foo::bar", "foo", "bar", "baz", ""]
+        );
+    }
+}
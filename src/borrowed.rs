@@ -0,0 +1,398 @@
+//! Interner for strings whose storage is owned outside of the interner.
+//!
+//! The [`BorrowedInterner`] only stores references to interned strings and
+//! leaves the ownership of their storage to the user, e.g. to an arena.
+
+use crate::{dedup::make_hash, DefaultSymbol, Symbol};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    iter::{Enumerate, FusedIterator},
+    marker::PhantomData,
+    slice,
+};
+use hashbrown::{
+    hash_map::{DefaultHashBuilder, RawEntryMut},
+    HashMap,
+};
+
+/// Data structure to intern and resolve strings that are stored outside of the interner.
+///
+/// Unlike the [`StringInterner`](`crate::StringInterner`) this interner never
+/// allocates storage for interned strings itself. Instead the storage of new
+/// strings is obtained from the user, e.g. from an arena that outlives the
+/// interner and frees all strings at once.
+///
+/// # Example
+///
+/// ```
+/// use string_interner::BorrowedInterner;
+///
+/// // Stand-in for an arena, e.g. a bump allocator or a memory-mapped file.
+/// let arena = String::from("Tiger Horse Tiger");
+/// let mut interner = <BorrowedInterner>::new();
+/// let symbols = arena
+///     .split(' ')
+///     .map(|word| interner.get_or_intern_in(word, |_| word))
+///     .collect::<Vec<_>>();
+/// assert_eq!(symbols[0], symbols[2]);
+/// let tiger = interner.resolve(symbols[0]).unwrap();
+/// drop(interner);
+/// assert_eq!(tiger, "Tiger");
+/// ```
+pub struct BorrowedInterner<'a, S = DefaultSymbol, H = DefaultHashBuilder> {
+    dedup: HashMap<S, (), ()>,
+    hasher: H,
+    strings: Vec<&'a str>,
+}
+
+impl<'a, S, H> Debug for BorrowedInterner<'a, S, H>
+where
+    S: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("BorrowedInterner")
+            .field("dedup", &self.dedup)
+            .field("strings", &self.strings)
+            .finish()
+    }
+}
+
+impl<'a, S, H> Default for BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a, S, H> Clone for BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            hasher: self.hasher.clone(),
+            strings: self.strings.clone(),
+        }
+    }
+}
+
+impl<'a, S, H> PartialEq for BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.strings == rhs.strings
+    }
+}
+
+impl<'a, S, H> Eq for BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<'a, S, H> BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `BorrowedInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+
+    /// Creates a new `BorrowedInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, Default::default())
+    }
+}
+
+impl<'a, S, H> BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `BorrowedInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::default(),
+            hasher: hash_builder,
+            strings: Vec::new(),
+        }
+    }
+
+    /// Creates a new empty `BorrowedInterner` with the given initial capacity and the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::with_capacity_and_hasher(cap, ()),
+            hasher: hash_builder,
+            strings: Vec::with_capacity(cap),
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.strings.len()
+    }
+
+    /// Returns `true` if the interner has no interned strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given string if any.
+    ///
+    /// Can be used to query if a string has already been interned without interning.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            strings,
+        } = self;
+        let hash = make_hash(hasher, string);
+        dedup
+            .raw_entry()
+            .from_hash(hash, |symbol| string == strings[symbol.to_usize()])
+            .map(|(&symbol, &())| symbol)
+    }
+
+    /// Interns the given string using `alloc` to obtain its storage.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// `alloc` is only called if `string` has not been interned before.
+    /// It must return a string equal to its input whose storage lives for `'a`.
+    ///
+    /// # Panics
+    ///
+    /// - If `alloc` returns a string that is not equal to its input.
+    /// - If the interner already interns the maximum number of strings possible
+    ///   by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern_in<F>(&mut self, string: &str, alloc: F) -> S
+    where
+        F: FnOnce(&str) -> &'a str,
+    {
+        let Self {
+            dedup,
+            hasher,
+            strings,
+        } = self;
+        let hash = make_hash(hasher, string);
+        let entry = dedup
+            .raw_entry_mut()
+            .from_hash(hash, |symbol| string == strings[symbol.to_usize()]);
+        match entry {
+            RawEntryMut::Occupied(occupied) => *occupied.key(),
+            RawEntryMut::Vacant(vacant) => {
+                let symbol = S::from_usize(strings.len());
+                let allocated = alloc(string);
+                assert_eq!(
+                    allocated, string,
+                    "encountered allocated string that differs from its input"
+                );
+                strings.push(allocated);
+                vacant.insert_with_hasher(hash, symbol, (), |symbol| {
+                    make_hash(hasher, strings[symbol.to_usize()])
+                });
+                symbol
+            }
+        }
+    }
+
+    /// Interns the given string that outlives the interner without copying it.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// Only a reference to `string` is stored if it has not been interned before.
+    /// This makes the interner a pure deduplication index over externally owned
+    /// data, e.g. a memory-mapped file.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use string_interner::BorrowedInterner;
+    ///
+    /// let document = String::from("Tiger Horse Tiger");
+    /// let mut interner = <BorrowedInterner>::new();
+    /// let symbols = document
+    ///     .split(' ')
+    ///     .map(|word| interner.get_or_intern_borrowed(word))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(symbols[0], symbols[2]);
+    /// let horse = interner.resolve(symbols[1]).unwrap();
+    /// assert_eq!(horse.as_ptr(), document[6..].as_ptr());
+    /// ```
+    #[inline]
+    pub fn get_or_intern_borrowed(&mut self, string: &'a str) -> S {
+        self.get_or_intern_in(string, |_| string)
+    }
+
+    /// Shrink capacity to fit the interned strings exactly.
+    pub fn shrink_to_fit(&mut self) {
+        self.strings.shrink_to_fit();
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    ///
+    /// # Note
+    ///
+    /// The returned string is not bound to the lifetime of the interner.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&'a str> {
+        self.strings.get(symbol.to_usize()).copied()
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, 'a, S> {
+        Iter {
+            strings: self.strings.iter().enumerate(),
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<'b, 'a, S, H> IntoIterator for &'b BorrowedInterner<'a, S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    type Item = (S, &'a str);
+    type IntoIter = Iter<'b, 'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the interned strings of a [`BorrowedInterner`] and their symbols.
+pub struct Iter<'b, 'a, S> {
+    strings: Enumerate<slice::Iter<'b, &'a str>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'b, 'a, S> Iterator for Iter<'b, 'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.strings.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.strings
+            .next()
+            .map(|(index, &string)| (S::from_usize(index), string))
+    }
+}
+
+impl<'b, 'a, S> DoubleEndedIterator for Iter<'b, 'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.strings
+            .next_back()
+            .map(|(index, &string)| (S::from_usize(index), string))
+    }
+}
+
+impl<'b, 'a, S> ExactSizeIterator for Iter<'b, 'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.strings.len()
+    }
+}
+
+impl<'b, 'a, S> FusedIterator for Iter<'b, 'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{boxed::Box, string::String};
+
+    #[test]
+    fn get_or_intern_in_works() {
+        let arena = ["a", "b"].map(String::from);
+        let mut interner = <BorrowedInterner>::new();
+        let mut calls = 0;
+        let mut alloc = |string: &str| {
+            calls += 1;
+            arena.iter().find(|s| *s == string).unwrap().as_str()
+        };
+        let a = interner.get_or_intern_in("a", &mut alloc);
+        let b = interner.get_or_intern_in("b", &mut alloc);
+        assert_eq!(interner.get_or_intern_in("a", &mut alloc), a);
+        assert_eq!(calls, 2);
+        assert_eq!(interner.get("b"), Some(b));
+        assert_eq!(interner.get("c"), None);
+        let resolved = interner.resolve(a).unwrap();
+        drop(interner);
+        assert_eq!(resolved, "a");
+        assert_eq!(resolved.as_ptr(), arena[0].as_ptr());
+    }
+
+    #[test]
+    fn get_or_intern_borrowed_works() {
+        let document = String::from("a b a");
+        let mut interner = <BorrowedInterner>::new();
+        let symbols = document
+            .split(' ')
+            .map(|word| interner.get_or_intern_borrowed(word))
+            .collect::<Vec<_>>();
+        assert_eq!(symbols[0], symbols[2]);
+        assert_eq!(interner.len(), 2);
+        let a = interner.resolve(symbols[2]).unwrap();
+        assert_eq!(a.as_ptr(), document.as_ptr());
+        assert_eq!(interner.resolve(symbols[1]), Some("b"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_or_intern_in_invalid_alloc_panics() {
+        let mut interner = <BorrowedInterner>::new();
+        interner.get_or_intern_in("a", |_| Box::leak(Box::from("b")));
+    }
+}
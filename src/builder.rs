@@ -0,0 +1,127 @@
+//! Builder to configure a [`StringInterner`] before its construction.
+
+use crate::{backend::Backend, StringInterner, Symbol};
+use core::{hash::BuildHasher, marker::PhantomData};
+use hashbrown::hash_map::DefaultHashBuilder;
+
+/// Builder to configure the hasher, capacity and limits of a [`StringInterner`].
+///
+/// Created via [`StringInterner::builder`] or [`StringInternerBuilder::new`].
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::{DefaultBackend, InternError, StringInternerBuilder};
+/// # use string_interner::DefaultHashBuilder;
+/// let mut interner = StringInternerBuilder::<DefaultBackend>::new()
+///     .hasher(DefaultHashBuilder::default())
+///     .capacity(100)
+///     .max_len(5)
+///     .finish();
+/// assert!(interner.hashmap_capacity() >= 100);
+/// assert!(interner.try_get_or_intern("Tiger").is_ok());
+/// assert_eq!(interner.try_get_or_intern("Elephant"), Err(InternError::TooLong));
+/// ```
+pub struct StringInternerBuilder<B, H = DefaultHashBuilder> {
+    hasher: H,
+    capacity: usize,
+    max_len: Option<usize>,
+    marker: PhantomData<fn() -> B>,
+}
+
+impl<B, H> Default for StringInternerBuilder<B, H>
+where
+    H: Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, H> StringInternerBuilder<B, H>
+where
+    H: Default,
+{
+    /// Creates a new builder with the default hasher, no capacity and no limits.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self {
+            hasher: H::default(),
+            capacity: 0,
+            max_len: None,
+            marker: PhantomData,
+        }
+    }
+}
+
+impl<B, H> StringInternerBuilder<B, H> {
+    /// Sets the hasher of the built interner.
+    #[inline]
+    pub fn hasher<H2>(self, hasher: H2) -> StringInternerBuilder<B, H2> {
+        StringInternerBuilder {
+            hasher,
+            capacity: self.capacity,
+            max_len: self.max_len,
+            marker: PhantomData,
+        }
+    }
+
+    /// Sets the number of strings the built interner can hold without reallocating.
+    #[inline]
+    pub fn capacity(mut self, capacity: usize) -> Self {
+        self.capacity = capacity;
+        self
+    }
+
+    /// Sets the maximum length in bytes of strings interned by the built interner.
+    ///
+    /// See [`StringInterner::set_max_str_len`] for details.
+    #[inline]
+    pub fn max_len(mut self, max_len: usize) -> Self {
+        self.max_len = Some(max_len);
+        self
+    }
+}
+
+impl<B, H> StringInternerBuilder<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    /// Creates the configured [`StringInterner`].
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn finish(self) -> StringInterner<B, H> {
+        let mut interner = StringInterner::with_capacity_and_hasher(self.capacity, self.hasher);
+        interner.set_max_str_len(self.max_len);
+        interner
+    }
+}
+
+#[cfg(all(test, feature = "backends"))]
+mod tests {
+    use super::*;
+    use crate::{DefaultBackend, DefaultStringInterner, InternError};
+
+    #[test]
+    fn builder_works() {
+        let hasher = DefaultHashBuilder::default();
+        let mut interner = <DefaultStringInterner>::builder()
+            .capacity(10)
+            .hasher(hasher.clone())
+            .max_len(3)
+            .finish();
+        assert!(interner.hashmap_capacity() >= 10);
+        assert_eq!(interner.max_str_len(), Some(3));
+        assert_eq!(interner.hash_string("a"), hasher.hash_one("a"));
+        assert!(interner.try_get_or_intern("abc").is_ok());
+        assert_eq!(
+            interner.try_get_or_intern("abcd"),
+            Err(InternError::TooLong)
+        );
+        let interner = StringInternerBuilder::<DefaultBackend>::default().finish();
+        assert!(interner == <DefaultStringInterner>::new());
+        assert_eq!(interner.max_str_len(), None);
+    }
+}
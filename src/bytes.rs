@@ -0,0 +1,455 @@
+//! Interner for byte slices that are not required to be valid UTF-8.
+//!
+//! The [`ByteInterner`] works like the [`StringInterner`](`crate::StringInterner`)
+//! but interns and resolves `&[u8]` instead of `&str`.
+
+use crate::{
+    dedup::{make_hash, Dedup, KeyStorage},
+    DefaultSymbol, Symbol,
+};
+use alloc::vec::Vec;
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    iter::{Enumerate, FromIterator, FusedIterator},
+    marker::PhantomData,
+    slice,
+};
+use hashbrown::hash_map::{DefaultHashBuilder, RawEntryMut};
+
+/// Data structure to intern and resolve byte slices.
+///
+/// Caches byte slices efficiently and associates them with unique symbols.
+/// All interned bytes are accumulated into a single contiguous buffer.
+///
+/// # Example
+///
+/// ```
+/// use string_interner::ByteInterner;
+///
+/// let mut interner = <ByteInterner>::new();
+/// let sym0 = interner.get_or_intern(b"\xFF\xFE");
+/// let sym1 = interner.get_or_intern(b"Tiger");
+/// let sym2 = interner.get_or_intern(b"\xFF\xFE");
+/// assert_ne!(sym0, sym1);
+/// assert_eq!(sym0, sym2); // same!
+/// assert_eq!(interner.resolve(sym0), Some(&b"\xFF\xFE"[..]));
+/// ```
+pub struct ByteInterner<S = DefaultSymbol, H = DefaultHashBuilder> {
+    dedup: Dedup<S>,
+    hasher: H,
+    storage: ByteStorage<S>,
+}
+
+/// Contiguous storage of the byte slices of a [`ByteInterner`].
+#[derive(Clone, PartialEq, Eq)]
+struct ByteStorage<S> {
+    ends: Vec<usize>,
+    buffer: Vec<u8>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<S> ByteStorage<S> {
+    /// Creates a new storage with room for `cap` byte slices of `bytes_cap` total bytes.
+    #[inline]
+    fn with_capacity(cap: usize, bytes_cap: usize) -> Self {
+        Self {
+            ends: Vec::with_capacity(cap),
+            buffer: Vec::with_capacity(bytes_cap),
+            marker: Default::default(),
+        }
+    }
+
+    /// Returns the start index of the bytes at `index` within the buffer.
+    #[inline]
+    fn start_of(&self, index: usize) -> usize {
+        self.ends.get(index.wrapping_sub(1)).copied().unwrap_or(0)
+    }
+}
+
+impl<S> ByteStorage<S>
+where
+    S: Symbol,
+{
+    /// Pushes the given bytes into the storage and returns their symbol.
+    #[inline]
+    fn push(&mut self, bytes: &[u8]) -> S {
+        let symbol = S::from_usize(self.ends.len());
+        self.buffer.extend_from_slice(bytes);
+        self.ends.push(self.buffer.len());
+        symbol
+    }
+
+    /// Returns the bytes for the given `symbol` if any.
+    #[inline]
+    fn resolve(&self, symbol: S) -> Option<&[u8]> {
+        let index = symbol.to_usize();
+        let to = *self.ends.get(index)?;
+        Some(&self.buffer[self.start_of(index)..to])
+    }
+}
+
+impl<S> KeyStorage for ByteStorage<S>
+where
+    S: Symbol,
+{
+    type Symbol = S;
+    type Key = [u8];
+
+    #[inline]
+    unsafe fn resolve_key_unchecked(&self, symbol: S) -> &[u8] {
+        let index = symbol.to_usize();
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        let to = unsafe { *self.ends.get_unchecked(index) };
+        // SAFETY: The spans of valid symbols are always within the buffer.
+        unsafe { self.buffer.get_unchecked(self.start_of(index)..to) }
+    }
+}
+
+/// Formats the interned byte slices as a map from the `usize` values of their symbols.
+///
+/// The byte slices are listed in symbol order, e.g. `{0: [255], 1: [97, 98]}`.
+impl<S, H> Debug for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(symbol, bytes)| (symbol.to_usize(), bytes)),
+            )
+            .finish()
+    }
+}
+
+impl<S, H> Default for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, H> Clone for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            hasher: self.hasher.clone(),
+            storage: self.storage.clone(),
+        }
+    }
+}
+
+impl<S, H> PartialEq for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn eq(&self, rhs: &Self) -> bool {
+        self.storage == rhs.storage
+    }
+}
+
+impl<S, H> Eq for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+}
+
+impl<S, H> ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `ByteInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+
+    /// Creates a new `ByteInterner` with the given initial capacity.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity(cap: usize) -> Self {
+        Self::with_capacity_and_hasher(cap, Default::default())
+    }
+}
+
+impl<S, H> ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `ByteInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: Dedup::default(),
+            hasher: hash_builder,
+            storage: ByteStorage::with_capacity(0, 0),
+        }
+    }
+
+    /// Creates a new empty `ByteInterner` with the given initial capacity and the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
+        // According to google the approx. word length is 5.
+        let default_word_len = 5;
+        Self {
+            dedup: Dedup::with_capacity(cap),
+            hasher: hash_builder,
+            storage: ByteStorage::with_capacity(cap, cap * default_word_len),
+        }
+    }
+
+    /// Returns the number of byte slices interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.storage.ends.len()
+    }
+
+    /// Returns `true` if the byte interner has no interned byte slices.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given bytes if any.
+    ///
+    /// Can be used to query if bytes have already been interned without interning.
+    #[inline]
+    pub fn get<T>(&self, bytes: T) -> Option<S>
+    where
+        T: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            storage,
+        } = self;
+        let hash = make_hash(hasher, bytes);
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our storage making them valid.
+        unsafe { dedup.get(storage, hash, bytes) }
+    }
+
+    /// Interns the given bytes.
+    ///
+    /// Returns a symbol for resolution into the original bytes.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of byte slices possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, bytes: T) -> S
+    where
+        T: AsRef<[u8]>,
+    {
+        let bytes = bytes.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            storage,
+        } = self;
+        let hash = make_hash(hasher, bytes);
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our storage making them valid.
+        match unsafe { dedup.entry(storage, hash, bytes) } {
+            RawEntryMut::Occupied(occupied) => *occupied.key(),
+            RawEntryMut::Vacant(vacant) => {
+                let symbol = storage.push(bytes);
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our storage making them valid.
+                unsafe { Dedup::insert_vacant(vacant, hasher, storage, hash, symbol) };
+                symbol
+            }
+        }
+    }
+
+    /// Shrink capacity to fit the interned byte slices exactly.
+    pub fn shrink_to_fit(&mut self) {
+        let Self {
+            dedup,
+            hasher,
+            storage,
+        } = self;
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our storage making them valid.
+        unsafe { dedup.shrink_to(hasher, storage, 0) };
+        storage.ends.shrink_to_fit();
+        storage.buffer.shrink_to_fit();
+    }
+
+    /// Returns the bytes for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&[u8]> {
+        self.storage.resolve(symbol)
+    }
+
+    /// Returns the bytes for the given `symbol` without performing any checks.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to provide this method with `symbol`s
+    /// that are valid for the [`ByteInterner`].
+    #[inline]
+    pub unsafe fn resolve_unchecked(&self, symbol: S) -> &[u8] {
+        // SAFETY: The function is marked unsafe so that the caller guarantees
+        //         that required invariants are checked.
+        unsafe { self.storage.resolve_key_unchecked(symbol) }
+    }
+
+    /// Returns an iterator that yields all interned byte slices and their symbols.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, S> {
+        Iter {
+            buffer: &self.storage.buffer,
+            start: 0,
+            ends: self.storage.ends.iter().enumerate(),
+            marker: Default::default(),
+        }
+    }
+}
+
+impl<S, H, T> FromIterator<T> for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+    T: AsRef<[u8]>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let (capacity, _) = iter.size_hint();
+        let mut interner = Self::with_capacity(capacity);
+        interner.extend(iter);
+        interner
+    }
+}
+
+impl<S, H, T> Extend<T> for ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+    T: AsRef<[u8]>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        for bytes in iter {
+            self.get_or_intern(bytes);
+        }
+    }
+}
+
+impl<'a, S, H> IntoIterator for &'a ByteInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    type Item = (S, &'a [u8]);
+    type IntoIter = Iter<'a, S>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Iterator over the interned byte slices of a [`ByteInterner`] and their symbols.
+pub struct Iter<'a, S> {
+    buffer: &'a [u8],
+    start: usize,
+    ends: Enumerate<slice::Iter<'a, usize>>,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S> Iterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    type Item = (S, &'a [u8]);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.ends.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.ends.next().map(|(id, &to)| {
+            let from = core::mem::replace(&mut self.start, to);
+            (S::from_usize(id), &self.buffer[from..to])
+        })
+    }
+}
+
+impl<'a, S> ExactSizeIterator for Iter<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.ends.len()
+    }
+}
+
+impl<'a, S> FusedIterator for Iter<'a, S> where S: Symbol {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::format;
+
+    #[test]
+    fn intern_non_utf8_works() {
+        let mut interner = <ByteInterner>::new();
+        let a = interner.get_or_intern([0xC0, 0x80]);
+        let b = interner.get_or_intern(b"");
+        let c = interner.get_or_intern([0xFF]);
+        assert_eq!(interner.get_or_intern([0xC0, 0x80]), a);
+        assert_eq!(interner.get(b""), Some(b));
+        assert_eq!(interner.get([0xFE]), None);
+        assert_eq!(interner.len(), 3);
+        assert_eq!(interner.resolve(a), Some(&[0xC0, 0x80][..]));
+        assert_eq!(interner.resolve(b), Some(&[][..]));
+        assert_eq!(unsafe { interner.resolve_unchecked(c) }, &[0xFF]);
+        assert_eq!(
+            interner.iter().map(|(_, bytes)| bytes).collect::<Vec<_>>(),
+            [&[0xC0, 0x80][..], &[][..], &[0xFF][..]]
+        );
+    }
+
+    #[test]
+    fn debug_works() {
+        let interner = <ByteInterner>::from_iter([&[0xFF][..], b"ab"]);
+        assert_eq!(format!("{interner:?}"), "{0: [255], 1: [97, 98]}");
+    }
+
+    #[test]
+    fn shrink_to_fit_works() {
+        let mut interner = <ByteInterner>::with_capacity(100);
+        interner.get_or_intern(b"a");
+        interner.get_or_intern(b"a");
+        interner.shrink_to_fit();
+        assert_eq!(interner.len(), 1);
+        assert!(interner.dedup.capacity() < 100);
+        assert_eq!(interner.get(b"a").map(Symbol::to_usize), Some(0));
+    }
+}
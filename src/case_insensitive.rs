@@ -0,0 +1,223 @@
+use crate::{backend::Backend, Symbol};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::{BuildHasher, Hasher},
+};
+use hashbrown::{
+    hash_map::{DefaultHashBuilder, RawEntryMut},
+    HashMap,
+};
+
+/// Creates the `u64` hash value of the ASCII lowercase form of `string`.
+fn make_hash_ignore_case(builder: &impl BuildHasher, string: &str) -> u64 {
+    let state = &mut builder.build_hasher();
+    for byte in string.bytes() {
+        state.write_u8(byte.to_ascii_lowercase());
+    }
+    // Same as `str`'s `Hash` implementation to prevent prefix collisions.
+    state.write_u8(0xFF);
+    state.finish()
+}
+
+/// Data structure to intern and resolve strings ignoring their ASCII case.
+///
+/// Strings that only differ in their ASCII case are associated to the same symbol.
+/// Symbols resolve to the spelling of the string that has been interned first.
+///
+/// # Note
+///
+/// Only ASCII case is ignored, Unicode case folding is not supported.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::CaseInsensitiveInterner;
+/// let mut interner = <CaseInsensitiveInterner>::new();
+/// let sym0 = interner.get_or_intern("Select");
+/// let sym1 = interner.get_or_intern("SELECT");
+/// assert_eq!(sym0, sym1);
+/// assert_eq!(interner.resolve(sym1), Some("Select"));
+/// ```
+pub struct CaseInsensitiveInterner<B = crate::DefaultBackend, H = DefaultHashBuilder>
+where
+    B: Backend,
+{
+    dedup: HashMap<<B as Backend>::Symbol, (), ()>,
+    hasher: H,
+    backend: B,
+}
+
+impl<B, H> Debug for CaseInsensitiveInterner<B, H>
+where
+    B: Backend + Debug,
+    <B as Backend>::Symbol: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("CaseInsensitiveInterner")
+            .field("dedup", &self.dedup)
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+impl<B, H> Default for CaseInsensitiveInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, H> Clone for CaseInsensitiveInterner<B, H>
+where
+    B: Backend + Clone,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            hasher: self.hasher.clone(),
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<B, H> CaseInsensitiveInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `CaseInsensitiveInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<B, H> CaseInsensitiveInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    /// Creates a new empty `CaseInsensitiveInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::default(),
+            hasher: hash_builder,
+            backend: B::default(),
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.dedup.len()
+    }
+
+    /// Returns `true` if the string interner has no interned strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given string ignoring its ASCII case if any.
+    #[inline]
+    pub fn get<T>(&self, string: T) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            backend,
+        } = self;
+        let hash = make_hash_ignore_case(hasher, string);
+        dedup
+            .raw_entry()
+            .from_hash(hash, |symbol| {
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                string.eq_ignore_ascii_case(unsafe { backend.resolve_unchecked(*symbol) })
+            })
+            .map(|(&symbol, &())| symbol)
+    }
+
+    /// Interns the given string ignoring its ASCII case.
+    ///
+    /// Returns a symbol for resolution into the first interned spelling of the string.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern<T>(&mut self, string: T) -> <B as Backend>::Symbol
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let Self {
+            dedup,
+            hasher,
+            backend,
+        } = self;
+        let hash = make_hash_ignore_case(hasher, string);
+        let entry = dedup.raw_entry_mut().from_hash(hash, |symbol| {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            string.eq_ignore_ascii_case(unsafe { backend.resolve_unchecked(*symbol) })
+        });
+        match entry {
+            RawEntryMut::Occupied(occupied) => *occupied.key(),
+            RawEntryMut::Vacant(vacant) => {
+                let symbol = backend.intern(string);
+                vacant.insert_with_hasher(hash, symbol, (), |symbol| {
+                    // SAFETY: This is safe because we only operate on symbols that
+                    //         we receive from our backend making them valid.
+                    let string = unsafe { backend.resolve_unchecked(*symbol) };
+                    make_hash_ignore_case(hasher, string)
+                });
+                symbol
+            }
+        }
+    }
+
+    /// Returns the first interned spelling of the string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        self.backend.resolve(symbol)
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> <B as Backend>::Iter<'_> {
+        self.backend.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn ignore_case_works() {
+        let mut interner = <CaseInsensitiveInterner>::new();
+        let a = interner.get_or_intern("Foo");
+        let b = interner.get_or_intern("bar");
+        assert_eq!(interner.get_or_intern("foo"), a);
+        assert_eq!(interner.get_or_intern("FOO"), a);
+        assert_eq!(interner.get("BAR"), Some(b));
+        assert_eq!(interner.get("fo"), None);
+        assert_eq!(interner.get("Straße"), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(a), Some("Foo"));
+        assert_eq!(interner.resolve(b), Some("bar"));
+    }
+}
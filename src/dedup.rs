@@ -0,0 +1,285 @@
+//! Deduplication table shared by the [`StringInterner`](`crate::StringInterner`)
+//! and the [`ByteInterner`](`crate::ByteInterner`).
+//!
+//! The table only stores symbols. Candidate keys are hashed and compared by
+//! resolving the stored symbols via the storage of the interner so that no
+//! references into the storage are kept.
+
+use crate::{backend::Backend, Symbol};
+use core::hash::{BuildHasher, Hash};
+use hashbrown::{
+    hash_map::{RawEntryMut, RawVacantEntryMut},
+    HashMap,
+};
+
+/// Creates the `u64` hash value for the given value using the given hash builder.
+pub(crate) fn make_hash<T>(builder: &impl BuildHasher, value: &T) -> u64
+where
+    T: ?Sized + Hash,
+{
+    use core::hash::Hasher as _;
+    let state = &mut builder.build_hasher();
+    value.hash(state);
+    state.finish()
+}
+
+/// Storage that resolves the symbols of a [`Dedup`] table into their keys.
+pub(crate) trait KeyStorage {
+    /// The symbol used by the storage.
+    type Symbol: Symbol;
+    /// The interned keys.
+    type Key: ?Sized + Hash + Eq;
+
+    /// Resolves the given symbol to its key without performing any checks.
+    ///
+    /// # Safety
+    ///
+    /// The caller must ensure that `symbol` has been created by the storage.
+    unsafe fn resolve_key_unchecked(&self, symbol: Self::Symbol) -> &Self::Key;
+}
+
+impl<B> KeyStorage for B
+where
+    B: Backend,
+{
+    type Symbol = <B as Backend>::Symbol;
+    type Key = str;
+
+    #[inline]
+    unsafe fn resolve_key_unchecked(&self, symbol: Self::Symbol) -> &str {
+        // SAFETY: The caller guarantees that `symbol` is valid.
+        unsafe { self.resolve_unchecked(symbol) }
+    }
+}
+
+/// Returns a function computing the hash of the key of a symbol of `storage`.
+///
+/// # Safety
+///
+/// The returned function must only be used on symbols created by `storage`.
+#[inline]
+pub(crate) unsafe fn hash_symbol_with<'a, St, H>(
+    hasher: &'a H,
+    storage: &'a St,
+) -> impl Fn(&<St as KeyStorage>::Symbol) -> u64 + 'a
+where
+    St: KeyStorage,
+    H: BuildHasher,
+{
+    move |&symbol| {
+        // SAFETY: The caller guarantees that `symbol` is valid.
+        let key = unsafe { storage.resolve_key_unchecked(symbol) };
+        make_hash(hasher, key)
+    }
+}
+
+/// Table deduplicating interned keys by the symbols of their storage.
+///
+/// # Note
+///
+/// The table neither owns its hasher nor its storage. Both are provided per
+/// operation so that the table can be borrowed independently of the storage,
+/// e.g. while interning a new key into the storage. Operations that compare or
+/// rehash keys are `unsafe` since all symbols of the table must be valid for
+/// the provided storage and all hashes must be computed by the provided hasher.
+#[derive(Debug, Clone)]
+pub(crate) struct Dedup<S> {
+    table: HashMap<S, (), ()>,
+}
+
+impl<S> Default for Dedup<S> {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            table: HashMap::default(),
+        }
+    }
+}
+
+impl<S> Dedup<S> {
+    /// Creates a new table with room for at least `cap` symbols.
+    #[inline]
+    pub(crate) fn with_capacity(cap: usize) -> Self {
+        Self {
+            table: HashMap::with_capacity_and_hasher(cap, ()),
+        }
+    }
+
+    /// Returns the number of symbols in the table.
+    #[inline]
+    pub(crate) fn len(&self) -> usize {
+        self.table.len()
+    }
+
+    /// Returns the number of symbols the table can hold without reallocating.
+    #[inline]
+    pub(crate) fn capacity(&self) -> usize {
+        self.table.capacity()
+    }
+
+    /// Removes all symbols from the table keeping its capacity.
+    #[inline]
+    pub(crate) fn clear(&mut self) {
+        self.table.clear();
+    }
+}
+
+impl<S> Dedup<S>
+where
+    S: Symbol,
+{
+    /// Returns an iterator over the symbols of the table in arbitrary order.
+    #[inline]
+    pub(crate) fn symbols(&self) -> impl Iterator<Item = S> + '_ {
+        self.table.keys().copied()
+    }
+
+    /// Retains only the symbols for which `f` returns `true`.
+    #[inline]
+    pub(crate) fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(S) -> bool,
+    {
+        self.table.retain(|&symbol, ()| f(symbol));
+    }
+
+    /// Returns the symbol of `key` with the precomputed `hash` if any.
+    ///
+    /// # Safety
+    ///
+    /// All symbols of the table must be valid for `storage`.
+    #[inline]
+    pub(crate) unsafe fn get<St>(&self, storage: &St, hash: u64, key: &St::Key) -> Option<S>
+    where
+        St: KeyStorage<Symbol = S>,
+    {
+        self.table
+            .raw_entry()
+            .from_hash(hash, |&symbol| {
+                // SAFETY: The caller guarantees that all symbols are valid.
+                key == unsafe { storage.resolve_key_unchecked(symbol) }
+            })
+            .map(|(&symbol, &())| symbol)
+    }
+
+    /// Returns the entry of `key` with the precomputed `hash`.
+    ///
+    /// Use [`hash_symbol_with`] to insert into a vacant entry.
+    ///
+    /// # Safety
+    ///
+    /// All symbols of the table must be valid for `storage`.
+    #[inline]
+    pub(crate) unsafe fn entry<St>(
+        &mut self,
+        storage: &St,
+        hash: u64,
+        key: &St::Key,
+    ) -> RawEntryMut<'_, S, (), ()>
+    where
+        St: KeyStorage<Symbol = S>,
+    {
+        self.table.raw_entry_mut().from_hash(hash, |&symbol| {
+            // SAFETY: The caller guarantees that all symbols are valid.
+            key == unsafe { storage.resolve_key_unchecked(symbol) }
+        })
+    }
+
+    /// Inserts `symbol` with the precomputed `hash` of its key without checking for duplicates.
+    ///
+    /// # Safety
+    ///
+    /// All symbols of the table and `symbol` must be valid for `storage`.
+    #[inline]
+    pub(crate) unsafe fn insert<St, H>(&mut self, hasher: &H, storage: &St, hash: u64, symbol: S)
+    where
+        St: KeyStorage<Symbol = S>,
+        H: BuildHasher,
+    {
+        // SAFETY: The caller guarantees that all symbols are valid.
+        let rehash = unsafe { hash_symbol_with(hasher, storage) };
+        self.table
+            .raw_table_mut()
+            .insert(hash, (symbol, ()), |(symbol, ())| rehash(symbol));
+    }
+
+    /// Inserts `symbol` into the vacant `entry` with the precomputed `hash` of its key.
+    ///
+    /// # Safety
+    ///
+    /// All symbols of the table of `entry` and `symbol` must be valid for `storage`.
+    #[inline]
+    pub(crate) unsafe fn insert_vacant<St, H>(
+        entry: RawVacantEntryMut<'_, S, (), ()>,
+        hasher: &H,
+        storage: &St,
+        hash: u64,
+        symbol: S,
+    ) where
+        St: KeyStorage<Symbol = S>,
+        H: BuildHasher,
+    {
+        // SAFETY: The caller guarantees that all symbols are valid.
+        let rehash = unsafe { hash_symbol_with(hasher, storage) };
+        entry.insert_with_hasher(hash, symbol, (), rehash);
+    }
+
+    /// Removes `symbol` whose key has the precomputed `hash` from the table.
+    ///
+    /// Returns `true` if `symbol` has been in the table.
+    #[inline]
+    pub(crate) fn remove(&mut self, hash: u64, symbol: S) -> bool {
+        match self
+            .table
+            .raw_entry_mut()
+            .from_hash(hash, |&candidate| candidate == symbol)
+        {
+            RawEntryMut::Occupied(occupied) => {
+                occupied.remove();
+                true
+            }
+            RawEntryMut::Vacant(_) => false,
+        }
+    }
+
+    /// Tries to reserve capacity for at least `additional` more symbols.
+    ///
+    /// # Safety
+    ///
+    /// All symbols of the table must be valid for `storage`.
+    #[inline]
+    pub(crate) unsafe fn try_reserve<St, H>(
+        &mut self,
+        hasher: &H,
+        storage: &St,
+        additional: usize,
+    ) -> Result<(), hashbrown::TryReserveError>
+    where
+        St: KeyStorage<Symbol = S>,
+        H: BuildHasher,
+    {
+        // SAFETY: The caller guarantees that all symbols are valid.
+        let rehash = unsafe { hash_symbol_with(hasher, storage) };
+        self.table
+            .raw_table_mut()
+            .try_reserve(additional, |(symbol, ())| rehash(symbol))
+    }
+
+    /// Shrinks the table while keeping room for at least `min_capacity` symbols.
+    ///
+    /// # Safety
+    ///
+    /// All symbols of the table must be valid for `storage`.
+    #[inline]
+    pub(crate) unsafe fn shrink_to<St, H>(&mut self, hasher: &H, storage: &St, min_capacity: usize)
+    where
+        St: KeyStorage<Symbol = S>,
+        H: BuildHasher,
+    {
+        // SAFETY: The caller guarantees that all symbols are valid.
+        let rehash = unsafe { hash_symbol_with(hasher, storage) };
+        self.table
+            .raw_table_mut()
+            .shrink_to(min_capacity, |(symbol, ())| rehash(symbol));
+    }
+}
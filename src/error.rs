@@ -0,0 +1,62 @@
+//! Error types of the string interner.
+
+use core::{fmt, fmt::Display};
+
+/// Errors that can occur when trying to reserve capacity for a
+/// [`StringInterner`](`crate::StringInterner`).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum TryReserveError {
+    /// Failed to reserve capacity for the deduplication of strings.
+    Dedup(hashbrown::TryReserveError),
+    /// Failed to reserve capacity in the backend.
+    Backend(alloc::collections::TryReserveError),
+}
+
+impl Display for TryReserveError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Dedup(hashbrown::TryReserveError::CapacityOverflow) => {
+                write!(
+                    f,
+                    "capacity overflow while reserving deduplication capacity"
+                )
+            }
+            Self::Dedup(hashbrown::TryReserveError::AllocError { .. }) => {
+                write!(
+                    f,
+                    "allocation failure while reserving deduplication capacity"
+                )
+            }
+            Self::Backend(error) => write!(f, "failed to reserve backend capacity: {error}"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for TryReserveError {}
+
+/// Errors that can occur when trying to intern a string into a
+/// [`StringInterner`](`crate::StringInterner`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InternError {
+    /// The interner already interns the maximum number of strings
+    /// possible by the chosen symbol type.
+    OutOfSymbols,
+    /// A string was not assigned the symbol it was expected to be interned at.
+    UnexpectedSymbol,
+    /// The string exceeds the maximum string length of the interner.
+    TooLong,
+}
+
+impl Display for InternError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::OutOfSymbols => write!(f, "ran out of symbols"),
+            Self::UnexpectedSymbol => write!(f, "string was not interned at its expected symbol"),
+            Self::TooLong => write!(f, "string exceeds the maximum string length"),
+        }
+    }
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for InternError {}
@@ -1,21 +1,31 @@
-use crate::{backend::Backend, Symbol};
+#[cfg(feature = "stats")]
+use crate::InternStats;
+use crate::{
+    backend::{Backend, ContiguousBackend},
+    dedup::{make_hash, Dedup},
+    InternError, Resolver, StringInternerBuilder, Symbol, TryReserveError,
+};
+#[cfg(feature = "observer")]
+use crate::{observer::Observer, InternEvent};
+use alloc::{borrow::Cow, sync::Arc};
+#[cfg(not(feature = "std"))]
+use alloc::{string::String, vec::Vec};
 use core::{
+    cmp::Ordering,
     fmt,
     fmt::{Debug, Formatter},
     hash::{BuildHasher, Hash, Hasher},
-    iter::FromIterator,
+    iter::{FromIterator, FusedIterator},
+    marker::PhantomData,
+    mem,
+    ops::{Index, Range},
+    ptr::NonNull,
+    str::{self, Utf8Error},
+};
+use hashbrown::{
+    hash_map::{DefaultHashBuilder, RawEntryMut, RawVacantEntryMut},
+    HashSet,
 };
-use hashbrown::{hash_map::DefaultHashBuilder, HashMap};
-
-/// Creates the `u64` hash value for the given value using the given hash builder.
-fn make_hash<T>(builder: &impl BuildHasher, value: &T) -> u64
-where
-    T: ?Sized + Hash,
-{
-    let state = &mut builder.build_hasher();
-    value.hash(state);
-    state.finish()
-}
 
 /// Data structure to intern and resolve strings.
 ///
@@ -32,33 +42,66 @@ pub struct StringInterner<B, H = DefaultHashBuilder>
 where
     B: Backend,
 {
-    dedup: HashMap<<B as Backend>::Symbol, (), ()>,
+    /// Deduplicates interned strings keyed by their symbols.
+    ///
+    /// Candidate strings are hashed and compared by resolving the stored
+    /// symbols via the backend so that no references into the backend are kept.
+    dedup: Dedup<<B as Backend>::Symbol>,
     hasher: H,
     backend: B,
+    /// The `usize` representations of all symbols that have been removed.
+    tombstones: HashSet<usize>,
+    /// The maximum length in bytes of newly interned strings.
+    max_str_len: usize,
+    /// The number of times the interner has been [cleared](`StringInterner::clear`).
+    ///
+    /// Used to detect [`Checkpoint`]s that have been created before a clear.
+    generation: usize,
+    #[cfg(feature = "stats")]
+    stats: InternStats,
+    #[cfg(feature = "observer")]
+    observer: Observer<<B as Backend>::Symbol>,
 }
 
+/// Formats the interned strings as a map from the `usize` values of their symbols.
+///
+/// The strings are listed in symbol order, e.g. `{0: "foo", 1: "bar"}`.
+/// [Removed](`StringInterner::remove`) strings are omitted.
 impl<B, H> Debug for StringInterner<B, H>
 where
-    B: Backend + Debug,
-    <B as Backend>::Symbol: Symbol + Debug,
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
 {
     fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
-        f.debug_struct("StringInterner")
-            .field("dedup", &self.dedup)
-            .field("backend", &self.backend)
+        f.debug_map()
+            .entries(
+                self.iter()
+                    .map(|(symbol, string)| (symbol.to_usize(), string)),
+            )
             .finish()
     }
 }
 
-#[cfg(feature = "backends")]
-impl Default for StringInterner<crate::DefaultBackend> {
+impl<B, H> Default for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+{
     #[cfg_attr(feature = "inline-more", inline)]
     fn default() -> Self {
         StringInterner::new()
     }
 }
 
+/// Clones the interner preserving all symbol assignments.
+///
+/// Every symbol of the original interner resolves to the same string in the
+/// clone and vice versa. This includes symbols of [removed](`StringInterner::remove`)
+/// strings which stay invalid in the clone.
+///
+/// Use [`StringInterner::clone_with_hasher`] if the hasher does not implement [`Clone`].
 impl<B, H> Clone for StringInterner<B, H>
 where
     B: Backend + Clone,
@@ -70,18 +113,30 @@ where
             dedup: self.dedup.clone(),
             hasher: self.hasher.clone(),
             backend: self.backend.clone(),
+            tombstones: self.tombstones.clone(),
+            max_str_len: self.max_str_len,
+            generation: self.generation,
+            #[cfg(feature = "stats")]
+            stats: self.stats,
+            #[cfg(feature = "observer")]
+            observer: self.observer.clone(),
         }
     }
 }
 
-impl<B, H> PartialEq for StringInterner<B, H>
+/// Compares the interned strings together with their assigned symbols.
+///
+/// The hashers are not compared so interners with different hashers can be equal.
+/// Use [`StringInterner::eq_unordered`] to compare only the sets of interned strings.
+impl<B, H, H2> PartialEq<StringInterner<B, H2>> for StringInterner<B, H>
 where
     B: Backend + PartialEq,
     <B as Backend>::Symbol: Symbol,
     H: BuildHasher,
+    H2: BuildHasher,
 {
-    fn eq(&self, rhs: &Self) -> bool {
-        self.len() == rhs.len() && self.backend == rhs.backend
+    fn eq(&self, rhs: &StringInterner<B, H2>) -> bool {
+        self.len() == rhs.len() && self.backend == rhs.backend && self.tombstones == rhs.tombstones
     }
 }
 
@@ -93,6 +148,67 @@ where
 {
 }
 
+/// Compares the interned strings together with their assigned symbols lexicographically.
+///
+/// The strings are compared in ascending order of the `usize` values of their
+/// symbols, i.e. in the order in which they have been interned. Strings that
+/// have been [removed](`StringInterner::remove`) are ordered after live strings
+/// with equal contents. This is consistent with the [`PartialEq`] implementation.
+///
+/// # Example
+///
+/// ```
+/// # use std::collections::BTreeSet;
+/// # use string_interner::DefaultStringInterner;
+/// let ab = <DefaultStringInterner>::from_iter(["a", "b"]);
+/// let ba = <DefaultStringInterner>::from_iter(["b", "a"]);
+/// let a = <DefaultStringInterner>::from_iter(["a"]);
+/// assert!(a < ab && ab < ba);
+/// let sorted = BTreeSet::from([ba.clone(), a.clone(), ab.clone()]);
+/// assert!(sorted.into_iter().eq([a, ab, ba]));
+/// ```
+impl<B, H, H2> PartialOrd<StringInterner<B, H2>> for StringInterner<B, H>
+where
+    B: Backend + PartialEq,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    H2: BuildHasher,
+{
+    fn partial_cmp(&self, rhs: &StringInterner<B, H2>) -> Option<Ordering> {
+        Some(self.slots().cmp(rhs.slots()))
+    }
+}
+
+impl<B, H> Ord for StringInterner<B, H>
+where
+    B: Backend + Eq,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    fn cmp(&self, rhs: &Self) -> Ordering {
+        self.slots().cmp(rhs.slots())
+    }
+}
+
+/// Hashes the interned strings and their symbols in the order of iteration.
+///
+/// This is consistent with the [`PartialEq`] implementation since equal
+/// interners yield the same strings with the same symbols in the same order.
+impl<B, H> Hash for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    fn hash<T: Hasher>(&self, state: &mut T) {
+        self.len().hash(state);
+        for (symbol, string) in self {
+            symbol.to_usize().hash(state);
+            string.hash(state);
+        }
+    }
+}
+
 impl<B, H> StringInterner<B, H>
 where
     B: Backend,
@@ -103,9 +219,16 @@ where
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn new() -> Self {
         Self {
-            dedup: HashMap::default(),
+            dedup: Dedup::default(),
             hasher: Default::default(),
             backend: B::default(),
+            tombstones: HashSet::default(),
+            max_str_len: usize::MAX,
+            generation: 0,
+            #[cfg(feature = "stats")]
+            stats: InternStats::default(),
+            #[cfg(feature = "observer")]
+            observer: Observer::default(),
         }
     }
 
@@ -113,10 +236,176 @@ where
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn with_capacity(cap: usize) -> Self {
         Self {
-            dedup: HashMap::with_capacity_and_hasher(cap, ()),
+            dedup: Dedup::with_capacity(cap),
             hasher: Default::default(),
             backend: B::with_capacity(cap),
+            tombstones: HashSet::default(),
+            max_str_len: usize::MAX,
+            generation: 0,
+            #[cfg(feature = "stats")]
+            stats: InternStats::default(),
+            #[cfg(feature = "observer")]
+            observer: Observer::default(),
+        }
+    }
+
+    /// Creates a new `StringInterner` for `cap` strings with a total of `bytes` bytes.
+    ///
+    /// # Note
+    ///
+    /// This allows to intern a known set of strings without any reallocations.
+    /// The deduplication table already accounts for its load factor so
+    /// `cap` should be the exact number of strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let strings = ["Tiger", "Horse", "Lion"];
+    /// let bytes = strings.iter().map(|s| s.len()).sum();
+    /// let mut interner = <DefaultStringInterner>::with_capacity_for(strings.len(), bytes);
+    /// let capacity = interner.byte_capacity();
+    /// interner.extend(strings);
+    /// assert_eq!(interner.byte_capacity(), capacity);
+    /// ```
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_capacity_for(cap: usize, bytes: usize) -> Self {
+        Self {
+            dedup: Dedup::with_capacity(cap),
+            hasher: Default::default(),
+            backend: B::with_capacity_for(cap, bytes),
+            tombstones: HashSet::default(),
+            max_str_len: usize::MAX,
+            generation: 0,
+            #[cfg(feature = "stats")]
+            stats: InternStats::default(),
+            #[cfg(feature = "observer")]
+            observer: Observer::default(),
+        }
+    }
+
+    /// Returns a [`StringInternerBuilder`] to configure a new `StringInterner`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let interner = <DefaultStringInterner>::builder().capacity(10).max_len(64).finish();
+    /// assert_eq!(interner.max_str_len(), Some(64));
+    /// ```
+    #[inline]
+    pub fn builder() -> StringInternerBuilder<B, H> {
+        StringInternerBuilder::new()
+    }
+
+    /// Creates a new `StringInterner` for at least `cap` strings and interns all of `iter`.
+    ///
+    /// # Note
+    ///
+    /// Both the deduplication table and the backend are reserved for the
+    /// greater of `cap` and the lower bound of the size hint of `iter` before
+    /// interning. Use this over [`FromIterator`] if the expected number of
+    /// strings is known but the iterator cannot provide a good size hint,
+    /// e.g. for lazily filtered input, to avoid rehashing while interning.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let lines = "Tiger\nHorse\n\nLion";
+    /// let strings = lines.lines().filter(|line| !line.is_empty());
+    /// let interner = <DefaultStringInterner>::from_iter_with_capacity(strings, 3);
+    /// assert_eq!(interner.len(), 3);
+    /// assert!(interner.hashmap_capacity() >= 3);
+    /// ```
+    pub fn from_iter_with_capacity<I, T>(iter: I, cap: usize) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let iter = iter.into_iter();
+        let (lower, _) = iter.size_hint();
+        let mut interner = Self::with_capacity(cap.max(lower));
+        interner.extend(iter);
+        interner
+    }
+
+    /// Creates a new `StringInterner` prefilled with the given strings.
+    ///
+    /// The strings are interned in order and duplicates are ignored.
+    ///
+    /// # Note
+    ///
+    /// For backends with contiguous symbols, e.g. the
+    /// [`StringBackend`](`crate::backend::StringBackend`) and the
+    /// [`BucketBackend`](`crate::backend::BucketBackend`), the `n` distinct
+    /// strings are guaranteed to occupy the symbols with values `0..n`
+    /// in the order of their first occurrence. Strings interned afterwards
+    /// receive the subsequent symbols. This allows to define constant
+    /// symbols for well-known strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, Symbol, symbol::SymbolU32};
+    /// const SELF: usize = 0;
+    /// const SUPER: usize = 1;
+    /// let mut interner = <DefaultStringInterner>::from_values(["self", "super", "self"]);
+    /// assert_eq!(interner.get("super").map(SymbolU32::to_usize), Some(SUPER));
+    /// assert_eq!(interner.get_or_intern("self").to_usize(), SELF);
+    /// assert_eq!(interner.get_or_intern("crate").to_usize(), 2);
+    /// ```
+    pub fn from_values<I, T>(values: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        values.into_iter().collect()
+    }
+
+    /// Creates a new `StringInterner` from the given pairs of symbols and strings.
+    ///
+    /// Each string is interned at its paired symbol which makes it possible to
+    /// reconstruct an interner from the output of [`StringInterner::iter`]
+    /// or [`StringInterner::drain`] with the original symbol assignment.
+    ///
+    /// # Errors
+    ///
+    /// - [`InternError::UnexpectedSymbol`]: If a string is not assigned its
+    ///   paired symbol. This happens if the pairs are not ordered by their
+    ///   symbols, if their symbols are not contiguous or if a string is
+    ///   contained more than once.
+    /// - [`InternError::OutOfSymbols`]: If the symbol type cannot represent
+    ///   all of the strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, InternError};
+    /// let interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+    /// let entries = interner.iter().collect::<Vec<_>>();
+    /// assert_eq!(<DefaultStringInterner>::try_from_entries(entries.clone()), Ok(interner.clone()));
+    /// assert_eq!(
+    ///     <DefaultStringInterner>::try_from_entries(entries.into_iter().rev()),
+    ///     Err(InternError::UnexpectedSymbol),
+    /// );
+    /// ```
+    pub fn try_from_entries<I, T>(entries: I) -> Result<Self, InternError>
+    where
+        I: IntoIterator<Item = (<B as Backend>::Symbol, T)>,
+        T: AsRef<str>,
+    {
+        let entries = entries.into_iter();
+        let (capacity, _) = entries.size_hint();
+        let mut interner = Self::with_capacity(capacity);
+        for (expected, string) in entries {
+            let (symbol, is_new) =
+                interner.try_get_or_intern_using(string.as_ref(), B::try_intern)?;
+            if !is_new || symbol != expected {
+                return Err(InternError::UnexpectedSymbol);
+            }
         }
+        Ok(interner)
     }
 }
 
@@ -130,9 +419,16 @@ where
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn with_hasher(hash_builder: H) -> Self {
         StringInterner {
-            dedup: HashMap::default(),
+            dedup: Dedup::default(),
             hasher: hash_builder,
             backend: B::default(),
+            tombstones: HashSet::default(),
+            max_str_len: usize::MAX,
+            generation: 0,
+            #[cfg(feature = "stats")]
+            stats: InternStats::default(),
+            #[cfg(feature = "observer")]
+            observer: Observer::default(),
         }
     }
 
@@ -140,27 +436,70 @@ where
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn with_capacity_and_hasher(cap: usize, hash_builder: H) -> Self {
         StringInterner {
-            dedup: HashMap::with_capacity_and_hasher(cap, ()),
+            dedup: Dedup::with_capacity(cap),
             hasher: hash_builder,
             backend: B::with_capacity(cap),
+            tombstones: HashSet::default(),
+            max_str_len: usize::MAX,
+            generation: 0,
+            #[cfg(feature = "stats")]
+            stats: InternStats::default(),
+            #[cfg(feature = "observer")]
+            observer: Observer::default(),
         }
     }
 
     /// Returns the number of strings interned by the interner.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are not counted.
+    /// Therefore this is the number of symbols that can be resolved.
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn len(&self) -> usize {
         self.dedup.len()
     }
 
     /// Returns `true` if the string interner has no interned strings.
+    ///
+    /// This is the case if all interned strings have been [removed](`StringInterner::remove`).
     #[cfg_attr(feature = "inline-more", inline)]
     pub fn is_empty(&self) -> bool {
         self.len() == 0
     }
 
+    /// Returns the number of slots of the interner including the slots of removed strings.
+    ///
+    /// # Note
+    ///
+    /// This is the number of strings interned since the interner has been
+    /// created or [cleared](`StringInterner::clear`) the last time and it is
+    /// never less than [`StringInterner::len`]. For backends with contiguous
+    /// symbols all symbols ever returned have values below the slot count.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["Tiger", "Horse"]);
+    /// let tiger = interner.get("Tiger").unwrap();
+    /// interner.remove(tiger);
+    /// assert_eq!(interner.len(), 1);
+    /// assert_eq!(interner.slot_count(), 2);
+    /// ```
+    #[inline]
+    pub fn slot_count(&self) -> usize {
+        self.len() + self.tombstones.len()
+    }
+
     /// Returns the symbol for the given string if any.
     ///
     /// Can be used to query if a string has already been interned without interning.
+    ///
+    /// # Note
+    ///
+    /// The lookup hashes the borrowed `&str` and compares it against the
+    /// resolved strings of the candidate symbols. No owned or wrapped key is
+    /// constructed for the query. Key types that implement `Borrow<str>` but
+    /// not `AsRef<str>` can be looked up via `interner.get(Borrow::<str>::borrow(&key))`.
     #[inline]
     pub fn get<T>(&self, string: T) -> Option<<B as Backend>::Symbol>
     where
@@ -171,171 +510,3748 @@ where
             dedup,
             hasher,
             backend,
+            ..
         } = self;
         let hash = make_hash(hasher, string);
-        dedup
-            .raw_entry()
-            .from_hash(hash, |symbol| {
-                // SAFETY: This is safe because we only operate on symbols that
-                //         we receive from our backend making them valid.
-                string == unsafe { backend.resolve_unchecked(*symbol) }
-            })
-            .map(|(&symbol, &())| symbol)
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        unsafe { dedup.get(backend, hash, string) }
     }
 
-    /// Interns the given string.
+    /// Returns `true` if the given string has been interned.
     ///
-    /// This is used as backend by [`get_or_intern`][1] and [`get_or_intern_static`][2].
+    /// # Example
     ///
-    /// [1]: [`StringInterner::get_or_intern`]
-    /// [2]: [`StringInterner::get_or_intern_static`]
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let interner = <DefaultStringInterner>::from_iter(["Tiger", "Horse"]);
+    /// let animals = ["Tiger", "Lion", "Horse"];
+    /// let unknown = animals.iter().filter(|&&animal| !interner.contains(animal));
+    /// assert!(unknown.eq(&["Lion"]));
+    /// ```
+    #[inline]
+    pub fn contains<T>(&self, string: T) -> bool
+    where
+        T: AsRef<str>,
+    {
+        self.get(string).is_some()
+    }
+
+    /// Returns an iterator that yields the symbol of every given string if any.
+    ///
+    /// This is the lookup-only counterpart of [`StringInterner::intern_all`].
+    /// The strings are looked up lazily and none of them is interned.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let interner = <DefaultStringInterner>::from_iter(["Tiger", "Horse"]);
+    /// let symbols = interner.get_all(["Tiger", "Lion", "Horse"]).collect::<Vec<_>>();
+    /// assert_eq!(symbols, [interner.get("Tiger"), None, interner.get("Horse")]);
+    /// assert!(interner.get_all(["Horse", "Tiger"]).all(|symbol| symbol.is_some()));
+    /// ```
+    #[inline]
+    pub fn get_all<'a, I, T>(
+        &'a self,
+        strings: I,
+    ) -> impl Iterator<Item = Option<<B as Backend>::Symbol>> + 'a
+    where
+        I: IntoIterator<Item = T>,
+        I::IntoIter: 'a,
+        T: AsRef<str>,
+    {
+        strings.into_iter().map(|string| self.get(string))
+    }
+
+    /// Interns the given string if the backend is able to.
+    ///
+    /// This is used as backend by [`get_or_intern_using`][1] and [`try_get_or_intern`][2].
+    ///
+    /// Returns `true` alongside the symbol if the string has been newly interned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without modifying the interner if the string is not yet
+    /// interned and exceeds the maximum string length or if `intern_fn` fails.
+    ///
+    /// [1]: [`StringInterner::get_or_intern_using`]
+    /// [2]: [`StringInterner::try_get_or_intern`]
     #[cfg_attr(feature = "inline-more", inline)]
-    fn get_or_intern_using<T>(
+    fn try_get_or_intern_using<T, F>(
         &mut self,
         string: T,
-        intern_fn: fn(&mut B, T) -> <B as Backend>::Symbol,
-    ) -> <B as Backend>::Symbol
+        intern_fn: F,
+    ) -> Result<(<B as Backend>::Symbol, bool), InternError>
+    where
+        T: AsRef<str>,
+        F: FnOnce(&mut B, T) -> Option<<B as Backend>::Symbol>,
+    {
+        let hash = self.hash_string(string.as_ref());
+        self.try_get_or_intern_hashed_using(string, hash, intern_fn)
+    }
+
+    /// Interns the given string with its precomputed `hash` if the backend is able to.
+    ///
+    /// This is used as backend by [`try_get_or_intern_using`][1] and [`get_or_intern_hashed`][2].
+    ///
+    /// Returns `true` alongside the symbol if the string has been newly interned.
+    ///
+    /// # Errors
+    ///
+    /// Returns an error without modifying the interner if the string is not yet
+    /// interned and exceeds the maximum string length or if `intern_fn` fails.
+    ///
+    /// [1]: [`StringInterner::try_get_or_intern_using`]
+    /// [2]: [`StringInterner::get_or_intern_hashed`]
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn try_get_or_intern_hashed_using<T, F>(
+        &mut self,
+        string: T,
+        hash: u64,
+        intern_fn: F,
+    ) -> Result<(<B as Backend>::Symbol, bool), InternError>
     where
-        T: Copy + Hash + AsRef<str> + for<'a> PartialEq<&'a str>,
+        T: AsRef<str>,
+        F: FnOnce(&mut B, T) -> Option<<B as Backend>::Symbol>,
     {
         let Self {
             dedup,
             hasher,
             backend,
+            max_str_len,
+            ..
         } = self;
-        let hash = make_hash(hasher, string.as_ref());
-        let entry = dedup.raw_entry_mut().from_hash(hash, |symbol| {
-            // SAFETY: This is safe because we only operate on symbols that
-            //         we receive from our backend making them valid.
-            string == unsafe { backend.resolve_unchecked(*symbol) }
-        });
-        use hashbrown::hash_map::RawEntryMut;
-        let (&mut symbol, &mut ()) = match entry {
-            RawEntryMut::Occupied(occupied) => occupied.into_key_value(),
+        let str = string.as_ref();
+        #[cfg(any(feature = "stats", feature = "observer"))]
+        let len = str.len();
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        let entry = unsafe { dedup.entry(backend, hash, str) };
+        let result = match entry {
+            RawEntryMut::Occupied(occupied) => (*occupied.key(), false),
             RawEntryMut::Vacant(vacant) => {
-                let symbol = intern_fn(backend, string);
-                vacant.insert_with_hasher(hash, symbol, (), |symbol| {
-                    // SAFETY: This is safe because we only operate on symbols that
-                    //         we receive from our backend making them valid.
-                    let string = unsafe { backend.resolve_unchecked(*symbol) };
-                    make_hash(hasher, string)
-                })
+                if str.len() > *max_str_len {
+                    return Err(InternError::TooLong);
+                }
+                let symbol = intern_fn(backend, string).ok_or(InternError::OutOfSymbols)?;
+                // SAFETY: This is safe because we only operate on symbols that
+                //         we receive from our backend making them valid.
+                unsafe { Dedup::insert_vacant(vacant, hasher, backend, hash, symbol) };
+                (symbol, true)
             }
         };
-        symbol
+        #[cfg(feature = "stats")]
+        self.stats.record(len, result.1);
+        #[cfg(feature = "observer")]
+        self.observer.notify(result.0, len, result.1);
+        Ok(result)
     }
 
-    /// Interns the given string.
+    /// Returns the maximum length in bytes of newly interned strings if any.
+    #[inline]
+    pub fn max_str_len(&self) -> Option<usize> {
+        match self.max_str_len {
+            usize::MAX => None,
+            max_str_len => Some(max_str_len),
+        }
+    }
+
+    /// Sets the maximum length in bytes of newly interned strings.
+    ///
+    /// `None` removes the limit which is the default.
+    ///
+    /// # Note
+    ///
+    /// This allows to bound the memory consumed by untrusted input.
+    /// Strings exceeding the limit are rejected without interning them:
+    /// [`StringInterner::try_get_or_intern`] returns [`InternError::TooLong`]
+    /// for them whereas the panicking interning methods panic.
+    /// Strings that have been interned before the limit was set are kept
+    /// and interning them again still yields their symbols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, InternError};
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// interner.set_max_str_len(Some(5));
+    /// assert!(interner.try_get_or_intern("Tiger").is_ok());
+    /// assert_eq!(interner.try_get_or_intern("Elephant"), Err(InternError::TooLong));
+    /// assert_eq!(interner.get("Elephant"), None);
+    /// ```
+    #[inline]
+    pub fn set_max_str_len(&mut self, max_str_len: Option<usize>) {
+        self.max_str_len = max_str_len.unwrap_or(usize::MAX);
+    }
+
+    /// Returns a reference to the hasher of the interner.
+    #[inline]
+    pub fn hasher(&self) -> &H {
+        &self.hasher
+    }
+
+    /// Returns the hash of the given string as computed by the hasher of the interner.
+    ///
+    /// The returned hash can be used with [`StringInterner::get_or_intern_hashed`]
+    /// to avoid hashing the same string repeatedly.
+    ///
+    /// # Note
+    ///
+    /// The hash equals [`BuildHasher::hash_one`] of the hasher for `string`.
+    /// Therefore it can be computed without an interner and it is the same
+    /// for all interners with equal hashers, e.g. shards constructed via
+    /// [`StringInterner::with_hasher`] from clones of one seeded hasher.
+    #[inline]
+    pub fn hash_string(&self, string: &str) -> u64 {
+        make_hash(&self.hasher, string)
+    }
+
+    /// Interns the given string using its precomputed `hash`.
     ///
     /// Returns a symbol for resolution into the original string.
     ///
+    /// # Note
+    ///
+    /// The `hash` must have been computed by [`StringInterner::hash_string`]
+    /// of an interner with an equal hasher for the same string. Otherwise the
+    /// interner might fail to deduplicate strings and behave erratically,
+    /// though safely. This is checked in debug builds.
+    ///
+    /// To trust externally computed hashes, e.g. a stable hash shared by
+    /// multiple interners, use a hasher that computes this hash.
+    ///
     /// # Panics
     ///
     /// If the interner already interns the maximum number of strings possible
     /// by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let hash = interner.hash_string("Tiger");
+    /// let sym0 = interner.get_or_intern_hashed("Tiger", hash);
+    /// let sym1 = interner.get_or_intern_hashed("Tiger", hash);
+    /// assert_eq!(sym0, sym1);
+    /// assert_eq!(interner.get("Tiger"), Some(sym0));
+    /// ```
     #[inline]
-    pub fn get_or_intern<T>(&mut self, string: T) -> <B as Backend>::Symbol
+    pub fn get_or_intern_hashed<T>(&mut self, string: T, hash: u64) -> <B as Backend>::Symbol
     where
         T: AsRef<str>,
     {
-        self.get_or_intern_using(string.as_ref(), B::intern)
+        let string = string.as_ref();
+        debug_assert_eq!(
+            hash,
+            self.hash_string(string),
+            "encountered invalid hash for string"
+        );
+        match self.try_get_or_intern_hashed_using(string, hash, |backend, string| {
+            Some(backend.intern(string))
+        }) {
+            Ok((symbol, _)) => symbol,
+            Err(error) => panic!("failed to intern string: {error}"),
+        }
     }
 
-    /// Interns the given `'static` string.
+    /// Interns the given string.
+    ///
+    /// This is used as backend by [`get_or_intern`][1] and [`get_or_intern_static`][2].
+    ///
+    /// Returns `true` alongside the symbol if the string has been newly interned.
+    ///
+    /// [1]: [`StringInterner::get_or_intern`]
+    /// [2]: [`StringInterner::get_or_intern_static`]
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn get_or_intern_using<T>(
+        &mut self,
+        string: T,
+        intern_fn: fn(&mut B, T) -> <B as Backend>::Symbol,
+    ) -> (<B as Backend>::Symbol, bool)
+    where
+        T: AsRef<str>,
+    {
+        match self
+            .try_get_or_intern_using(string, |backend, string| Some(intern_fn(backend, string)))
+        {
+            Ok(result) => result,
+            Err(error) => panic!("failed to intern string: {error}"),
+        }
+    }
+
+    /// Interns the given string.
     ///
     /// Returns a symbol for resolution into the original string.
     ///
     /// # Note
     ///
-    /// This is more efficient than [`StringInterner::get_or_intern`] since it might
-    /// avoid some memory allocations if the backends supports this.
+    /// Accepts any string type implementing `AsRef<str>`, e.g. `&str`, `String`,
+    /// `&String` or `Cow<str>`. The string is only borrowed and its contents are
+    /// copied into the backend only if it has not been interned before, so no
+    /// allocation happens if the string is already interned.
     ///
     /// # Panics
     ///
     /// If the interner already interns the maximum number of strings possible
-    /// by the chosen symbol type.
+    /// by the chosen symbol type, e.g. `u16::MAX` strings for
+    /// [`SymbolU16`](`crate::symbol::SymbolU16`), or if the string is not yet
+    /// interned and exceeds the
+    /// [maximum string length](`StringInterner::set_max_str_len`) of the interner.
+    /// Use [`StringInterner::try_get_or_intern`] to handle these cases gracefully.
     #[inline]
-    pub fn get_or_intern_static(&mut self, string: &'static str) -> <B as Backend>::Symbol {
-        self.get_or_intern_using(string, B::intern_static)
-    }
-
-    /// Shrink backend capacity to fit the interned strings exactly.
-    pub fn shrink_to_fit(&mut self) {
-        self.backend.shrink_to_fit()
+    pub fn get_or_intern<T>(&mut self, string: T) -> <B as Backend>::Symbol
+    where
+        T: AsRef<str>,
+    {
+        self.get_or_intern_using(string.as_ref(), B::intern).0
     }
 
-    /// Returns the string for the given `symbol`` if any.
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// - [`InternError::OutOfSymbols`]: If the interner already interns the
+    ///   maximum number of strings possible by the chosen symbol type.
+    /// - [`InternError::TooLong`]: If the string is not yet interned and exceeds
+    ///   the [maximum string length](`StringInterner::set_max_str_len`) of the interner.
+    ///
+    /// In both cases the interner is not modified.
     #[inline]
-    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
-        self.backend.resolve(symbol)
+    pub fn try_get_or_intern<T>(&mut self, string: T) -> Result<<B as Backend>::Symbol, InternError>
+    where
+        T: AsRef<str>,
+    {
+        self.try_get_or_intern_using(string.as_ref(), B::try_intern)
+            .map(|(symbol, _)| symbol)
     }
 
-    /// Returns the string for the given `symbol` without performing any checks.
+    /// Interns the given string.
     ///
-    /// # Safety
+    /// Returns a symbol for resolution into the original string and `true`
+    /// if the string has not been interned before.
     ///
-    /// It is the caller's responsibility to provide this method with `symbol`s
-    /// that are valid for the [`StringInterner`].
+    /// # Note
+    ///
+    /// This is more efficient than calling [`StringInterner::get`] before
+    /// [`StringInterner::get_or_intern`] since the string is only hashed once.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
     #[inline]
-    pub unsafe fn resolve_unchecked(&self, symbol: <B as Backend>::Symbol) -> &str {
-        unsafe { self.backend.resolve_unchecked(symbol) }
+    pub fn get_or_intern_full<T>(&mut self, string: T) -> (<B as Backend>::Symbol, bool)
+    where
+        T: AsRef<str>,
+    {
+        self.get_or_intern_using(string.as_ref(), B::intern)
+    }
+
+    /// Gets the entry of the given string for in-place inspection and interning.
+    ///
+    /// # Note
+    ///
+    /// The string is hashed and looked up only once. Interning a
+    /// [`VacantEntry`] does not repeat the look-up.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, Entry};
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let mut new_strings = Vec::new();
+    /// for string in ["Tiger", "Horse", "Tiger"] {
+    ///     let symbol = match interner.entry(string) {
+    ///         Entry::Occupied(symbol) => symbol,
+    ///         Entry::Vacant(vacant) => {
+    ///             new_strings.push(vacant.key().to_owned());
+    ///             vacant.intern()
+    ///         }
+    ///     };
+    ///     assert_eq!(interner.resolve(symbol), Some(string));
+    /// }
+    /// assert_eq!(new_strings, ["Tiger", "Horse"]);
+    /// ```
+    #[inline]
+    pub fn entry<'a>(&'a mut self, string: &'a str) -> Entry<'a, B, H> {
+        let hash = self.hash_string(string);
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            max_str_len,
+            #[cfg(feature = "stats")]
+            stats,
+            #[cfg(feature = "observer")]
+            observer,
+            ..
+        } = self;
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        let entry = unsafe { dedup.entry(backend, hash, string) };
+        match entry {
+            RawEntryMut::Occupied(occupied) => {
+                #[cfg(feature = "stats")]
+                stats.record(string.len(), false);
+                #[cfg(feature = "observer")]
+                observer.notify(*occupied.key(), string.len(), false);
+                Entry::Occupied(*occupied.key())
+            }
+            RawEntryMut::Vacant(vacant) => Entry::Vacant(VacantEntry {
+                entry: vacant,
+                hasher,
+                backend,
+                string,
+                hash,
+                max_str_len: *max_str_len,
+                #[cfg(feature = "stats")]
+                stats,
+                #[cfg(feature = "observer")]
+                observer,
+            }),
+        }
+    }
+
+    /// Interns the given string and returns its symbol together with the interned string.
+    ///
+    /// # Note
+    ///
+    /// This avoids the additional look-up of calling [`StringInterner::resolve`]
+    /// after [`StringInterner::get_or_intern`]. The returned string borrows the
+    /// interner so it must be dropped before the interner can be mutated again.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let (symbol, string) = interner.get_or_intern_ref(String::from("Tiger"));
+    /// assert_eq!(string, "Tiger");
+    /// assert_eq!(interner.resolve(symbol), Some("Tiger"));
+    /// ```
+    #[inline]
+    pub fn get_or_intern_ref<T>(&mut self, string: T) -> (<B as Backend>::Symbol, &str)
+    where
+        T: AsRef<str>,
+    {
+        let symbol = self.get_or_intern(string);
+        // SAFETY: The symbol has just been returned by the backend of the interner.
+        let string = unsafe { self.backend.resolve_unchecked(symbol) };
+        (symbol, string)
+    }
+
+    /// Interns the given OS string if it is valid UTF-8.
+    ///
+    /// Returns a symbol for resolution into the original string or
+    /// `None` without modifying the interner if `string` is not valid UTF-8.
+    ///
+    /// # Note
+    ///
+    /// This allows to intern [`Path`](`std::path::Path`)s via
+    /// [`Path::as_os_str`](`std::path::Path::as_os_str`) without lossy conversions.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[cfg(feature = "std")]
+    #[inline]
+    pub fn get_or_intern_os<T>(&mut self, string: T) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<std::ffi::OsStr>,
+    {
+        string
+            .as_ref()
+            .to_str()
+            .map(|string| self.get_or_intern(string))
+    }
+
+    /// Interns the given bytes if they are valid UTF-8.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Errors
+    ///
+    /// If `bytes` are not valid UTF-8. In this case the interner is not modified.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let symbol = interner.get_or_intern_bytes(b"Tiger").unwrap();
+    /// assert_eq!(interner.resolve(symbol), Some("Tiger"));
+    /// assert!(interner.get_or_intern_bytes(b"\xFF").is_err());
+    /// assert_eq!(interner.len(), 1);
+    /// ```
+    #[inline]
+    pub fn get_or_intern_bytes<T>(&mut self, bytes: T) -> Result<<B as Backend>::Symbol, Utf8Error>
+    where
+        T: AsRef<[u8]>,
+    {
+        str::from_utf8(bytes.as_ref()).map(|string| self.get_or_intern(string))
+    }
+
+    /// Interns all given strings and returns their symbols in order.
+    ///
+    /// # Note
+    ///
+    /// Reserves capacity for the strings up front using the `size_hint` of `strings`.
+    /// Unlike [`Extend`] this returns the symbols of the interned strings.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let symbols = interner.intern_all(["a", "b", "a"]);
+    /// assert_eq!(symbols[0], symbols[2]);
+    /// assert_eq!(interner.resolve(symbols[1]), Some("b"));
+    /// ```
+    pub fn intern_all<I, T>(&mut self, strings: I) -> Vec<<B as Backend>::Symbol>
+    where
+        I: IntoIterator<Item = T>,
+        T: AsRef<str>,
+    {
+        let strings = strings.into_iter();
+        let (additional, _) = strings.size_hint();
+        // Reserving is only an optimization: running out of memory
+        // is going to be reported by interning the strings below.
+        let _ = self.try_reserve(additional);
+        strings.map(|string| self.get_or_intern(string)).collect()
+    }
+
+    /// Interns the given `'static` string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// This is more efficient than [`StringInterner::get_or_intern`] since it might
+    /// avoid some memory allocations if the backends supports this.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern_static(&mut self, string: &'static str) -> <B as Backend>::Symbol {
+        self.get_or_intern_using(string, B::intern_static).0
+    }
+
+    /// Interns the given copy-on-write string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// Owned strings are moved into the backend instead of being copied
+    /// if the backend supports this. Otherwise this is equivalent to
+    /// [`StringInterner::get_or_intern`].
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern_cow(&mut self, string: Cow<'_, str>) -> <B as Backend>::Symbol {
+        match string {
+            Cow::Borrowed(string) => self.get_or_intern(string),
+            Cow::Owned(string) => self.get_or_intern_using(string, B::intern_owned).0,
+        }
+    }
+
+    /// Interns the given shared string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// Backends that store shared strings, e.g. the
+    /// [`ArcBackend`](`crate::backend::ArcBackend`), keep `string` by bumping
+    /// its reference count instead of copying its contents. Otherwise this is
+    /// equivalent to [`StringInterner::get_or_intern`].
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::Arc;
+    /// # use string_interner::{backend::ArcBackend, StringInterner};
+    /// let mut interner = StringInterner::<ArcBackend>::new();
+    /// let tiger = Arc::<str>::from("Tiger");
+    /// let symbol = interner.get_or_intern_arc(Arc::clone(&tiger));
+    /// assert_eq!(interner.get_or_intern_arc(Arc::from("Tiger")), symbol);
+    /// assert_eq!(Arc::strong_count(&tiger), 2);
+    /// ```
+    #[inline]
+    pub fn get_or_intern_arc(&mut self, string: Arc<str>) -> <B as Backend>::Symbol {
+        self.get_or_intern_using(string, B::intern_arc).0
+    }
+
+    /// Interns the string `key` using `make` to construct its owned string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// `make` is only called if `key` has not been interned before. Its owned
+    /// string is moved into the backend instead of being copied if the backend
+    /// supports this, see [`StringInterner::get_or_intern_cow`].
+    ///
+    /// # Panics
+    ///
+    /// - If `make` returns a string that is not equal to `key`.
+    /// - If the interner already interns the maximum number of strings possible
+    ///   by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let tiger = interner.get_or_intern_with("Tiger", || String::from("Tiger"));
+    /// let again = interner.get_or_intern_with("Tiger", || unreachable!());
+    /// assert_eq!(tiger, again);
+    /// ```
+    #[inline]
+    pub fn get_or_intern_with<F>(&mut self, key: &str, make: F) -> <B as Backend>::Symbol
+    where
+        F: FnOnce() -> String,
+    {
+        match self.try_get_or_intern_using(key, |backend, key| {
+            let string = make();
+            assert_eq!(
+                string, key,
+                "encountered constructed string that differs from its key"
+            );
+            Some(backend.intern_owned(string))
+        }) {
+            Ok((symbol, _)) => symbol,
+            Err(error) => panic!("failed to intern string: {error}"),
+        }
+    }
+
+    /// Interns the given string without checking if it has been interned before.
+    ///
+    /// Returns the symbol of the newly interned string.
+    ///
+    /// # Note
+    ///
+    /// This skips the deduplication look-up which speeds up bulk loading
+    /// strings that are known to be unique, e.g. a deduplicated dictionary.
+    /// It is a logic error to push a string that is already interned: the
+    /// interner then holds the string twice and looking it up may yield
+    /// either of its symbols. This is checked in debug builds only.
+    ///
+    /// # Panics
+    ///
+    /// - If the string exceeds the maximum string length.
+    /// - If the interner already interns the maximum number of strings possible
+    ///   by the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let tiger = interner.push_unique("Tiger");
+    /// let horse = interner.push_unique("Horse");
+    /// assert_eq!(interner.get("Tiger"), Some(tiger));
+    /// assert_eq!(interner.get_or_intern("Horse"), horse);
+    /// ```
+    pub fn push_unique<T>(&mut self, string: T) -> <B as Backend>::Symbol
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        debug_assert!(
+            self.get(string).is_none(),
+            "encountered string that has already been interned"
+        );
+        let hash = self.hash_string(string);
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            max_str_len,
+            ..
+        } = self;
+        assert!(
+            string.len() <= *max_str_len,
+            "failed to intern string: {}",
+            InternError::TooLong
+        );
+        let symbol = match backend.try_intern(string) {
+            Some(symbol) => symbol,
+            None => panic!("failed to intern string: {}", InternError::OutOfSymbols),
+        };
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        unsafe { dedup.insert(hasher, backend, hash, symbol) };
+        #[cfg(feature = "stats")]
+        self.stats.record(string.len(), true);
+        #[cfg(feature = "observer")]
+        self.observer.notify(symbol, string.len(), true);
+        symbol
+    }
+
+    /// Removes the string associated to the given `symbol` from the interner.
+    ///
+    /// Returns the removed string if `symbol` referred to an interned string.
+    ///
+    /// # Note
+    ///
+    /// The slot of the removed string is tombstoned so that the symbols of all
+    /// other interned strings stay valid and removed symbols are never reused.
+    /// After removal [`StringInterner::resolve`] returns `None` for `symbol`,
+    /// [`StringInterner::len`] no longer counts it and iteration skips it.
+    /// Interning the same string again yields a new symbol.
+    ///
+    /// The memory used by the backend to store the removed string is not reclaimed.
+    pub fn remove(&mut self, symbol: <B as Backend>::Symbol) -> Option<String> {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            tombstones,
+            ..
+        } = self;
+        let string = backend.resolve(symbol)?;
+        let hash = make_hash(hasher, string);
+        if !dedup.remove(hash, symbol) {
+            return None;
+        }
+        tombstones.insert(symbol.to_usize());
+        Some(String::from(string))
+    }
+
+    /// Returns a [`Checkpoint`] of the current state of the interner.
+    ///
+    /// Use [`StringInterner::rollback`] to remove all strings interned after this call.
+    #[inline]
+    pub fn checkpoint(&self) -> Checkpoint {
+        Checkpoint {
+            len: self.slot_count(),
+            generation: self.generation,
+        }
+    }
+
+    /// Removes all strings interned since the given `checkpoint` was created.
+    ///
+    /// Does nothing if the interner has been [cleared](`StringInterner::clear`)
+    /// since `checkpoint` was created or if `checkpoint` is newer than the state
+    /// of the interner, e.g. if the interner has been rolled back to an older
+    /// checkpoint and fewer strings have been interned since.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`StringInterner::remove`] this reclaims the storage of the removed
+    /// strings if supported by the backend. The symbols of the removed strings
+    /// become invalid and are assigned again to strings interned afterwards.
+    ///
+    /// A checkpoint only records the number of strings. Like nested transactions
+    /// checkpoints should be rolled back in reverse order of their creation:
+    /// after rolling back to a checkpoint, rolling back to a checkpoint created
+    /// after it removes all strings beyond its length, including strings that
+    /// have been interned after the first rollback.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let checkpoint = interner.checkpoint();
+    /// let horse = interner.get_or_intern("Horse");
+    /// interner.rollback(checkpoint);
+    /// assert_eq!(interner.get("Horse"), None);
+    /// assert_eq!(interner.resolve(tiger), Some("Tiger"));
+    /// assert_eq!(interner.get_or_intern("Lion"), horse);
+    /// ```
+    pub fn rollback(&mut self, checkpoint: Checkpoint) {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            tombstones,
+            generation,
+            ..
+        } = self;
+        let slot_count = dedup.len() + tombstones.len();
+        if checkpoint.generation != *generation || checkpoint.len >= slot_count {
+            return;
+        }
+        // The `usize` value of a symbol is never less than the index of its string.
+        // Therefore all strings interned since the checkpoint are yielded last.
+        let removed = slot_count - checkpoint.len;
+        let skip = backend.iter_from(checkpoint.len).count() - removed;
+        for (symbol, string) in backend.iter_from(checkpoint.len).skip(skip) {
+            if tombstones.remove(&symbol.to_usize()) {
+                continue;
+            }
+            let hash = make_hash(hasher, string);
+            dedup.remove(hash, symbol);
+        }
+        backend.truncate(checkpoint.len);
+    }
+
+    /// Retains only the strings for which the predicate `f` returns `true`.
+    ///
+    /// All other strings are [removed](`StringInterner::remove`).
+    ///
+    /// # Note
+    ///
+    /// The symbols of retained strings are not changed and stay valid.
+    /// The symbols of removed strings become invalid and are never reused.
+    /// Therefore no remapping of symbols is required after this operation.
+    ///
+    /// The predicate is called exactly once per interned string in unspecified order.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["a", "bb", "c"]);
+    /// let c = interner.get("c").unwrap();
+    /// interner.retain(|_, string| string.len() == 1);
+    /// assert_eq!(interner.len(), 2);
+    /// assert_eq!(interner.get("bb"), None);
+    /// assert_eq!(interner.resolve(c), Some("c"));
+    /// ```
+    pub fn retain<F>(&mut self, mut f: F)
+    where
+        F: FnMut(<B as Backend>::Symbol, &str) -> bool,
+    {
+        let Self {
+            dedup,
+            backend,
+            tombstones,
+            ..
+        } = self;
+        dedup.retain(|symbol| {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            let string = unsafe { backend.resolve_unchecked(symbol) };
+            let keep = f(symbol, string);
+            if !keep {
+                tombstones.insert(symbol.to_usize());
+            }
+            keep
+        });
+    }
+
+    /// Removes all strings for which the predicate `f` returns `true` and returns them.
+    ///
+    /// The removed strings are yielded as owned strings alongside their symbols
+    /// in ascending order of their symbols.
+    ///
+    /// # Note
+    ///
+    /// Like [`StringInterner::retain`] the removed strings are tombstoned so
+    /// that the symbols of all other strings stay valid and removed symbols are
+    /// never reused. The predicate is called exactly once per interned string.
+    ///
+    /// The strings are removed eagerly, i.e. dropping the returned iterator
+    /// before it is exhausted does not keep any of them in the interner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["$0", "x", "$1"]);
+    /// let x = interner.get("x").unwrap();
+    /// let temporaries = interner
+    ///     .extract_if(|_, string| string.starts_with('$'))
+    ///     .map(|(_, string)| string)
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(temporaries, ["$0", "$1"]);
+    /// assert_eq!(interner.len(), 1);
+    /// assert_eq!(interner.resolve(x), Some("x"));
+    /// ```
+    pub fn extract_if<F>(
+        &mut self,
+        mut f: F,
+    ) -> impl Iterator<Item = (<B as Backend>::Symbol, String)>
+    where
+        F: FnMut(<B as Backend>::Symbol, &str) -> bool,
+    {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            tombstones,
+            ..
+        } = self;
+        let mut extracted = Vec::new();
+        // Only strings removed before this call need to be skipped.
+        let has_tombstones = !tombstones.is_empty();
+        for (symbol, string) in backend.iter() {
+            if (has_tombstones && tombstones.contains(&symbol.to_usize())) || !f(symbol, string) {
+                continue;
+            }
+            let hash = make_hash(hasher, string);
+            dedup.remove(hash, symbol);
+            tombstones.insert(symbol.to_usize());
+            extracted.push((symbol, String::from(string)));
+        }
+        extracted.into_iter()
+    }
+
+    /// Returns `true` if both interners contain the same set of strings.
+    ///
+    /// Unlike the [`PartialEq`] implementation this ignores the symbols
+    /// assigned to the strings, their backends and their capacities.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{backend::BufferBackend, DefaultStringInterner, StringInterner};
+    /// let a = <DefaultStringInterner>::from_iter(["a", "b"]);
+    /// let b = <StringInterner<BufferBackend>>::from_iter(["b", "a"]);
+    /// assert!(a.eq_unordered(&b));
+    /// ```
+    pub fn eq_unordered<B2, H2>(&self, other: &StringInterner<B2, H2>) -> bool
+    where
+        B2: Backend,
+        <B2 as Backend>::Symbol: Symbol,
+        H2: BuildHasher,
+    {
+        self.len() == other.len() && self.iter().all(|(_, string)| other.get(string).is_some())
+    }
+
+    /// Clones the interner using `hasher` for the clone.
+    ///
+    /// # Note
+    ///
+    /// Like [`Clone`] this preserves all symbol assignments but it does not
+    /// require the hasher to implement [`Clone`] since the deduplication table
+    /// of the clone is rebuilt with `hasher`. This also allows to clone an
+    /// interner with a hasher of a different type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultHashBuilder, DefaultStringInterner};
+    /// let interner = <DefaultStringInterner>::from_iter(["Tiger", "Horse"]);
+    /// let clone = interner.clone_with_hasher(DefaultHashBuilder::default());
+    /// assert_eq!(clone, interner);
+    /// assert_eq!(clone.get("Horse"), interner.get("Horse"));
+    /// ```
+    pub fn clone_with_hasher<H2>(&self, hasher: H2) -> StringInterner<B, H2>
+    where
+        B: Clone,
+        H2: BuildHasher,
+    {
+        let backend = self.backend.clone();
+        let mut dedup = Dedup::with_capacity(self.dedup.len());
+        for symbol in self.dedup.symbols() {
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            let string = unsafe { backend.resolve_unchecked(symbol) };
+            let hash = make_hash(&hasher, string);
+            // SAFETY: The cloned backend resolves the same symbols.
+            unsafe { dedup.insert(&hasher, &backend, hash, symbol) };
+        }
+        StringInterner {
+            dedup,
+            hasher,
+            backend,
+            tombstones: self.tombstones.clone(),
+            max_str_len: self.max_str_len,
+            generation: self.generation,
+            #[cfg(feature = "stats")]
+            stats: self.stats,
+            #[cfg(feature = "observer")]
+            observer: self.observer.clone(),
+        }
+    }
+
+    /// Returns an iterator over all slots of the interner in the order of their symbols.
+    ///
+    /// Yields the `usize` value of the symbol, the string and whether it has been removed.
+    fn slots(&self) -> impl Iterator<Item = (usize, &str, bool)> + '_ {
+        self.backend
+            .iter()
+            .map(|(symbol, string)| (symbol.to_usize(), string, self.is_removed(symbol)))
+    }
+
+    /// Returns `true` if the given `symbol` refers to a removed string.
+    #[inline]
+    fn is_removed(&self, symbol: <B as Backend>::Symbol) -> bool {
+        !self.tombstones.is_empty() && self.tombstones.contains(&symbol.to_usize())
+    }
+
+    /// Interns all strings of `other` into `self`.
+    ///
+    /// Returns pairs mapping the symbols of `other` to the symbols of `self`
+    /// in ascending order of the symbols of `other`. Strings that are already
+    /// interned by `self` map to their existing symbols.
+    ///
+    /// # Panics
+    ///
+    /// If the interner runs out of symbols of the chosen symbol type.
+    pub fn merge<B2, H2>(
+        &mut self,
+        other: StringInterner<B2, H2>,
+    ) -> Vec<(<B2 as Backend>::Symbol, <B as Backend>::Symbol)>
+    where
+        B2: Backend,
+        H2: BuildHasher,
+    {
+        other
+            .iter()
+            .map(|(symbol, string)| (symbol, self.get_or_intern(string)))
+            .collect()
+    }
+
+    /// Interns all strings of `other` into `self` without mapping their symbols.
+    ///
+    /// # Note
+    ///
+    /// Use this instead of [`StringInterner::merge`] to accumulate the union
+    /// of the strings of both interners if the symbols of `other` are not needed.
+    /// Capacity for all strings of `other` is reserved up front.
+    ///
+    /// # Panics
+    ///
+    /// If the interner runs out of symbols of the chosen symbol type.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["Tiger", "Horse"]);
+    /// let other = <DefaultStringInterner>::from_iter(["Horse", "Lion"]);
+    /// interner.extend_from(&other);
+    /// assert!(interner.iter().map(|(_, s)| s).eq(["Tiger", "Horse", "Lion"]));
+    /// ```
+    pub fn extend_from<B2, H2>(&mut self, other: &StringInterner<B2, H2>)
+    where
+        B2: Backend,
+        H2: BuildHasher,
+    {
+        // Reserving is only an optimization so failures are deferred to interning.
+        let _ = self.try_reserve(other.len());
+        self.extend(other.iter().map(|(_, string)| string));
+    }
+
+    /// Tries to reserve capacity for at least `additional` more strings.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows or the allocator reports a failure.
+    /// In this case no strings are interned or lost, however, parts of the
+    /// requested capacity might have been reserved already.
+    pub fn try_reserve(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        let capacity = dedup.capacity();
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        unsafe { dedup.try_reserve(hasher, backend, additional) }
+            .map_err(TryReserveError::Dedup)?;
+        if let Err(error) = backend.try_reserve(additional) {
+            // Releasing the excess deduplication capacity is best effort only.
+            // SAFETY: This is safe because we only operate on symbols that
+            //         we receive from our backend making them valid.
+            unsafe { dedup.shrink_to(hasher, backend, capacity) };
+            return Err(TryReserveError::Backend(error));
+        }
+        Ok(())
+    }
+
+    /// Tries to reserve capacity for at least `additional` more bytes of string contents.
+    ///
+    /// # Note
+    ///
+    /// Together with [`StringInterner::try_reserve`] this allows to intern
+    /// a known amount of strings without reallocations. Backends that do not
+    /// pack string contents into a contiguous buffer ignore this request.
+    ///
+    /// # Errors
+    ///
+    /// If the capacity overflows or the allocator reports a failure.
+    /// In this case the capacity of the interner remains unchanged.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// interner.try_reserve_bytes(100).unwrap();
+    /// assert!(interner.byte_capacity() >= 100);
+    /// ```
+    pub fn try_reserve_bytes(&mut self, additional: usize) -> Result<(), TryReserveError> {
+        self.backend
+            .try_reserve_bytes(additional)
+            .map_err(TryReserveError::Backend)
+    }
+
+    /// Removes all interned strings from the interner.
+    ///
+    /// Keeps the allocated memory for reuse if supported by the backend.
+    ///
+    /// # Note
+    ///
+    /// All symbols previously returned by the interner become invalid
+    /// and may refer to newly interned strings after a clear.
+    pub fn clear(&mut self) {
+        self.dedup.clear();
+        self.backend.clear();
+        self.tombstones.clear();
+        self.generation = self.generation.wrapping_add(1);
+        #[cfg(feature = "stats")]
+        {
+            self.stats = InternStats::default();
+        }
+    }
+
+    /// Returns the deduplication statistics of the interner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, InternStats};
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// interner.extend(["Tiger", "Horse", "Tiger"]);
+    /// assert_eq!(
+    ///     interner.stats(),
+    ///     InternStats {
+    ///         total_interned: 3,
+    ///         unique: 2,
+    ///         bytes_saved: 5,
+    ///     }
+    /// );
+    /// ```
+    #[cfg(feature = "stats")]
+    #[inline]
+    pub fn stats(&self) -> InternStats {
+        self.stats
+    }
+
+    /// Sets the observer that is notified about every intern request.
+    ///
+    /// # Note
+    ///
+    /// The observer is called with [`InternEvent::Hit`] if the requested string
+    /// has been interned before and with [`InternEvent::Miss`] if it has been
+    /// newly interned, i.e. if storage has been allocated for it.
+    /// This allows to feed the behavior of the interner into metrics.
+    /// Look-ups that do not intern, e.g. [`StringInterner::get`], are not observed.
+    /// Clones of the interner share the observer.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use std::sync::{Arc, atomic::{AtomicUsize, Ordering}};
+    /// # use string_interner::{DefaultStringInterner, InternEvent};
+    /// let misses = Arc::new(AtomicUsize::new(0));
+    /// let counter = Arc::clone(&misses);
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// interner.set_intern_observer(move |event| {
+    ///     if let InternEvent::Miss(_, bytes) = event {
+    ///         counter.fetch_add(bytes, Ordering::Relaxed);
+    ///     }
+    /// });
+    /// interner.extend(["Tiger", "Horse", "Tiger"]);
+    /// assert_eq!(misses.load(Ordering::Relaxed), 10);
+    /// ```
+    #[cfg(feature = "observer")]
+    pub fn set_intern_observer<F>(&mut self, observer: F)
+    where
+        F: Fn(InternEvent<<B as Backend>::Symbol>) + Send + Sync + 'static,
+    {
+        self.observer.set(Some(Arc::new(observer)));
+    }
+
+    /// Removes the observer set via [`StringInterner::set_intern_observer`] if any.
+    #[cfg(feature = "observer")]
+    #[inline]
+    pub fn clear_intern_observer(&mut self) {
+        self.observer.set(None);
+    }
+
+    /// Removes all interned strings from the interner and returns them with their symbols.
+    ///
+    /// Semantically this is like `Vec::drain(..)`: the interner is left empty
+    /// but keeps its allocated memory for reuse if supported by the backend.
+    ///
+    /// # Note
+    ///
+    /// The strings are copied out of the backend one at a time while iterating.
+    /// The interner is [cleared](`StringInterner::clear`) when the returned
+    /// [`Drain`] iterator is dropped, even if it has not been fully consumed.
+    /// All symbols previously returned by the interner become invalid.
+    /// If the [`Drain`] iterator is leaked, e.g. via [`mem::forget`], the
+    /// interner keeps all of its strings.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+    /// let a = interner.get("a").unwrap();
+    /// let drained = interner.drain().collect::<Vec<_>>();
+    /// assert_eq!(drained[0], (a, String::from("a")));
+    /// assert!(interner.is_empty());
+    /// ```
+    pub fn drain(&mut self) -> Drain<'_, B, H> {
+        let interner = NonNull::from(self);
+        // SAFETY: The returned `Drain` exclusively borrows the interner and
+        //         only accesses it mutably after dropping this iterator.
+        let iter = unsafe { interner.as_ref() }.iter();
+        Drain {
+            iter: Some(iter),
+            interner,
+            marker: PhantomData,
+        }
+    }
+
+    /// Converts the interner into an iterator over the owned interned strings.
+    ///
+    /// The strings are yielded in the same order as by [`StringInterner::iter`].
+    ///
+    /// # Note
+    ///
+    /// Only the symbols are collected upfront. The strings are copied out of
+    /// the backend one at a time while iterating.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let interner = <DefaultStringInterner>::from_iter(["a", "b", "a"]);
+    /// let strings = interner.into_values().collect::<Vec<String>>();
+    /// assert_eq!(strings, ["a", "b"]);
+    /// ```
+    pub fn into_values(self) -> IntoValues<B> {
+        IntoValues {
+            iter: self.into_iter(),
+        }
+    }
+
+    /// Shrink backend and deduplication table capacity to fit the interned strings exactly.
+    pub fn shrink_to_fit(&mut self) {
+        self.shrink_dedup_to(0);
+        self.backend.shrink_to_fit();
+        self.tombstones.shrink_to_fit();
+    }
+
+    /// Shrink backend and deduplication table capacity while keeping room for
+    /// at least `min_capacity` strings.
+    ///
+    /// # Note
+    ///
+    /// This is useful to reclaim most of the memory after a spike of interned
+    /// strings while keeping headroom for subsequent strings. The capacity
+    /// is never shrunk below the number of interned strings.
+    pub fn shrink_to(&mut self, min_capacity: usize) {
+        self.shrink_dedup_to(min_capacity);
+        self.backend.shrink_to(min_capacity);
+        self.tombstones.shrink_to_fit();
+    }
+
+    /// Shrinks the deduplication table while keeping room for at least `min_capacity` strings.
+    fn shrink_dedup_to(&mut self, min_capacity: usize) {
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        unsafe { dedup.shrink_to(hasher, backend, min_capacity) };
+    }
+
+    /// Returns the total number of bytes of all strings stored by the interner.
+    ///
+    /// # Note
+    ///
+    /// This includes the bytes of [removed](`StringInterner::remove`) strings
+    /// since their storage is not reclaimed.
+    #[inline]
+    pub fn total_string_bytes(&self) -> usize {
+        self.backend.total_string_bytes()
+    }
+
+    /// Returns the number of string bytes the interner can hold without reallocating.
+    ///
+    /// # Note
+    ///
+    /// The exact meaning depends on the backend. For example the
+    /// [`BufferBackend`](`crate::backend::BufferBackend`) also stores the
+    /// lengths of its strings in the same storage.
+    #[inline]
+    pub fn byte_capacity(&self) -> usize {
+        self.backend.byte_capacity()
+    }
+
+    /// Returns the number of strings the deduplication hash map can hold without rehashing.
+    ///
+    /// # Note
+    ///
+    /// The interner rehashes its hash map once [`StringInterner::len`] exceeds
+    /// this capacity. Use [`StringInterner::try_reserve`] to rehash proactively.
+    #[inline]
+    pub fn hashmap_capacity(&self) -> usize {
+        self.dedup.capacity()
+    }
+
+    /// Returns the ratio of interned strings to the capacity of the deduplication hash map.
+    ///
+    /// Returns `0.0` if the hash map has not allocated yet. A load factor of
+    /// `1.0` means that the next newly interned string triggers a rehash.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// assert_eq!(interner.load_factor(), 0.0);
+    /// interner.get_or_intern("Tiger");
+    /// assert!(interner.load_factor() > 0.0);
+    /// assert!(interner.load_factor() <= 1.0);
+    /// ```
+    #[inline]
+    pub fn load_factor(&self) -> f64 {
+        match self.hashmap_capacity() {
+            0 => 0.0,
+            capacity => self.dedup.len() as f64 / capacity as f64,
+        }
+    }
+
+    /// Returns the approximate number of heap allocated bytes of the interner.
+    ///
+    /// This accounts for the allocated capacity of the backend as well as
+    /// an estimate of the allocation of the deduplication hash map.
+    ///
+    /// # Note
+    ///
+    /// Can be used to decide when to call [`StringInterner::shrink_to_fit`].
+    pub fn memory_usage(&self) -> usize {
+        // Hash tables store one control byte per bucket in addition to the entry.
+        let dedup_bytes = self.dedup.capacity() * (mem::size_of::<<B as Backend>::Symbol>() + 1);
+        let tombstone_bytes = self.tombstones.capacity() * (mem::size_of::<usize>() + 1);
+        self.backend.memory_usage() + dedup_bytes + tombstone_bytes
+    }
+
+    /// Returns the string for the given `symbol`` if any.
+    ///
+    /// Returns `None` if the string of `symbol` has been [removed](`StringInterner::remove`).
+    ///
+    /// # Note
+    ///
+    /// This method can be used as a function item, e.g. to build dispatch tables.
+    /// It cannot be a `const fn` since it calls trait methods of the [`Backend`]
+    /// and the [`Symbol`] which are not callable in `const` contexts on stable Rust.
+    /// The same applies to [`StringInterner::resolve_unchecked`].
+    ///
+    /// # Lifetimes
+    ///
+    /// The returned string borrows the interner, as do the strings yielded by
+    /// [`StringInterner::iter`]. Therefore it stays valid for as long as the
+    /// interner is not mutated and the compiler rejects holding it across
+    /// [`StringInterner::get_or_intern`], [`StringInterner::remove`] or
+    /// [`StringInterner::clear`]. This holds for every backend, even for
+    /// backends that move their strings when interning new ones, e.g. the
+    /// [`StringBackend`](`crate::backend::StringBackend`).
+    ///
+    /// Use the `SharedInterner` of the `sync` feature to keep resolved strings
+    /// across interning new strings since it interns via `&self`.
+    ///
+    /// ```compile_fail
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let resolved = interner.resolve(tiger).unwrap();
+    /// interner.get_or_intern("Horse"); // error: `interner` is borrowed by `resolved`
+    /// assert_eq!(resolved, "Tiger");
+    /// ```
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, DefaultSymbol};
+    /// let resolve: fn(&DefaultStringInterner, DefaultSymbol) -> Option<&str> =
+    ///     DefaultStringInterner::resolve;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let symbol = interner.get_or_intern("Tiger");
+    /// assert_eq!(resolve(&interner, symbol), Some("Tiger"));
+    /// ```
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        if self.is_removed(symbol) {
+            return None;
+        }
+        self.backend.resolve(symbol)
+    }
+
+    /// Returns the UTF-8 bytes of the string for the given `symbol` if any.
+    ///
+    /// Returns `None` if the string of `symbol` has been [removed](`StringInterner::remove`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let symbol = interner.get_or_intern("Tiger");
+    /// assert_eq!(interner.resolve_bytes(symbol), Some(&b"Tiger"[..]));
+    /// ```
+    #[inline]
+    pub fn resolve_bytes(&self, symbol: <B as Backend>::Symbol) -> Option<&[u8]> {
+        self.resolve(symbol).map(str::as_bytes)
+    }
+
+    /// Returns `true` if the given `symbol` refers to a string of the interner.
+    ///
+    /// Returns `false` if the string of `symbol` has been [removed](`StringInterner::remove`).
+    ///
+    /// # Note
+    ///
+    /// Can be used to validate symbols before using [`StringInterner::resolve_unchecked`].
+    #[inline]
+    pub fn contains_symbol(&self, symbol: <B as Backend>::Symbol) -> bool {
+        !self.is_removed(symbol) && self.backend.contains_symbol(symbol)
+    }
+
+    /// Returns the string for the given `symbol` without performing any checks.
+    ///
+    /// # Note
+    ///
+    /// In debug builds `symbol` is validated and an invalid `symbol` causes
+    /// a panic pointing at the caller. Release builds perform no checks.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to provide this method with `symbol`s
+    /// that are valid for the [`StringInterner`].
+    /// Symbols of [removed](`StringInterner::remove`) strings are not valid.
+    #[inline]
+    #[track_caller]
+    pub unsafe fn resolve_unchecked(&self, symbol: <B as Backend>::Symbol) -> &str {
+        debug_assert!(
+            self.contains_symbol(symbol),
+            "encountered invalid symbol for string interner"
+        );
+        // SAFETY: The caller guarantees that `symbol` is valid.
+        unsafe { self.backend.resolve_unchecked(symbol) }
+    }
+
+    /// Returns the strings for the given `symbols` if any.
+    ///
+    /// Each returned string is `None` if its symbol cannot be resolved.
+    #[inline]
+    pub fn resolve_many<const N: usize>(
+        &self,
+        symbols: [<B as Backend>::Symbol; N],
+    ) -> [Option<&str>; N] {
+        symbols.map(|symbol| self.resolve(symbol))
+    }
+
+    /// Returns an iterator that lazily resolves the given `symbols`.
+    ///
+    /// Each yielded string is `None` if its symbol cannot be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let symbols = vec![interner.get_or_intern("a"), interner.get_or_intern("b")];
+    /// let strings = interner.resolve_iter(symbols.iter().copied().rev());
+    /// assert!(strings.eq([Some("b"), Some("a")]));
+    /// ```
+    #[inline]
+    pub fn resolve_iter<I>(&self, symbols: I) -> ResolveIter<'_, B, H, I::IntoIter>
+    where
+        I: IntoIterator<Item = <B as Backend>::Symbol>,
+    {
+        ResolveIter {
+            interner: self,
+            symbols: symbols.into_iter(),
+        }
+    }
+
+    /// Returns the strings for the given `symbols` if all of them are valid.
+    ///
+    /// Validates all `symbols` once and then resolves them without further checks.
+    /// Returns `None` if any of the `symbols` cannot be resolved.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let a = interner.get_or_intern("a");
+    /// let b = interner.get_or_intern("b");
+    /// assert_eq!(interner.resolve_all([b, a, b]), Some(["b", "a", "b"]));
+    /// ```
+    #[inline]
+    pub fn resolve_all<const N: usize>(
+        &self,
+        symbols: [<B as Backend>::Symbol; N],
+    ) -> Option<[&str; N]> {
+        if !symbols.iter().all(|&symbol| self.contains_symbol(symbol)) {
+            return None;
+        }
+        // SAFETY: We just checked that all symbols refer to strings of the interner.
+        Some(symbols.map(|symbol| unsafe { self.backend.resolve_unchecked(symbol) }))
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    ///
+    /// # Note
+    ///
+    /// The strings are guaranteed to be yielded in ascending order of the
+    /// `usize` values of their symbols, which is the order in which they have
+    /// been interned. This holds for all provided backends and is independent
+    /// of the hasher of the interner. Consuming the interner via
+    /// [`IntoIterator`] yields the same symbols in the same order with their
+    /// strings owned.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, B> {
+        Iter::new(&self.backend, &self.tombstones, self.len())
+    }
+
+    /// Returns an iterator that yields the UTF-8 bytes of all interned strings and their symbols.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    /// The strings are yielded in the same order as by [`StringInterner::iter`].
+    #[inline]
+    pub fn iter_bytes(&self) -> IterBytes<'_, B> {
+        IterBytes { iter: self.iter() }
+    }
+
+    /// Returns an iterator over the interned strings whose symbols are within `range`.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    ///
+    /// # Note
+    ///
+    /// Since symbols are assigned in ascending order this yields all strings
+    /// interned since `range.start` and before `range.end`.
+    /// The iteration starts at `range.start` directly via [`Backend::iter_from`]
+    /// for all provided backends with contiguous symbols.
+    /// The [`BufferBackend`](`crate::backend::BufferBackend`) has to skip
+    /// all strings interned before `range.start` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+    /// let watermark = interner.get_or_intern("c");
+    /// let end = interner.get_or_intern("d");
+    /// interner.get_or_intern("e");
+    /// let strings = interner.symbols_in_range(watermark..end).map(|(_, s)| s);
+    /// assert!(strings.eq(["c"]));
+    /// ```
+    #[inline]
+    pub fn symbols_in_range(&self, range: Range<<B as Backend>::Symbol>) -> IterRange<'_, B> {
+        let start = range.start.to_usize();
+        IterRange {
+            iter: self.backend.iter_from(start),
+            tombstones: &self.tombstones,
+            start,
+            end: range.end.to_usize(),
+        }
+    }
+
+    /// Returns an iterator over the interned strings whose symbols are not less than `start`.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    ///
+    /// # Note
+    ///
+    /// This yields all strings interned since `start` and is equivalent to
+    /// [`StringInterner::symbols_in_range`] without an end of the range.
+    /// Therefore it does not visit the strings interned before `start`
+    /// for all provided backends with contiguous symbols.
+    /// `start` does not need to be the symbol of an interned string, e.g. it
+    /// may be a watermark created via [`Symbol::try_from_usize`] from the
+    /// [`StringInterner::slot_count`] of an earlier state of the interner.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+    /// let watermark = interner.get_or_intern("c");
+    /// interner.get_or_intern("d");
+    /// let strings = interner.iter_from(watermark).map(|(_, s)| s);
+    /// assert!(strings.eq(["c", "d"]));
+    /// ```
+    #[inline]
+    pub fn iter_from(&self, start: <B as Backend>::Symbol) -> IterRange<'_, B> {
+        let start = start.to_usize();
+        IterRange {
+            iter: self.backend.iter_from(start),
+            tombstones: &self.tombstones,
+            start,
+            end: usize::MAX,
+        }
+    }
+
+    /// Returns the symbol of the earliest interned string if any.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    #[inline]
+    pub fn first_symbol(&self) -> Option<<B as Backend>::Symbol> {
+        self.iter().next().map(|(symbol, _)| symbol)
+    }
+
+    /// Returns the symbol of the most recently interned string if any.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    ///
+    /// # Note
+    ///
+    /// Requires a backend with a double ended iterator to find the symbol
+    /// without iterating over all interned strings. For other backends use
+    /// `interner.iter_symbols().last()` instead.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// assert_eq!(interner.last_symbol(), None);
+    /// let tiger = interner.get_or_intern("Tiger");
+    /// let horse = interner.get_or_intern("Horse");
+    /// interner.get_or_intern("Tiger");
+    /// assert_eq!(interner.first_symbol(), Some(tiger));
+    /// assert_eq!(interner.last_symbol(), Some(horse));
+    /// ```
+    #[inline]
+    pub fn last_symbol<'a>(&'a self) -> Option<<B as Backend>::Symbol>
+    where
+        <B as Backend>::Iter<'a>: DoubleEndedIterator,
+    {
+        self.iter().next_back().map(|(symbol, _)| symbol)
+    }
+
+    /// Returns an iterator that yields the symbols of all interned strings.
+    ///
+    /// Strings that have been [removed](`StringInterner::remove`) are skipped.
+    /// The symbols are yielded in the same order as by [`StringInterner::iter`].
+    #[inline]
+    pub fn iter_symbols(&self) -> Symbols<'_, B> {
+        Symbols { iter: self.iter() }
+    }
+
+    /// Returns a shared reference to the backend of the interner.
+    ///
+    /// # Note
+    ///
+    /// This allows to use backend specific read-only APIs, e.g.
+    /// [`StringBackend::as_str`](`crate::backend::StringBackend::as_str`) to
+    /// export all interned strings at once. The backend still contains the
+    /// strings that have been [removed](`StringInterner::remove`) from the interner.
+    #[inline]
+    pub fn backend(&self) -> &B {
+        &self.backend
+    }
+
+    /// Converts the interner into a [`Resolver`] that can only resolve symbols.
+    ///
+    /// # Note
+    ///
+    /// This drops the data structures required for deduplication which
+    /// reduces the memory footprint for workloads that no longer intern
+    /// strings after an initial fill phase.
+    pub fn into_resolver(self) -> Resolver<B> {
+        let len = self.len();
+        Resolver::new(self.backend, self.tombstones, len)
+    }
+}
+
+impl<B, H> StringInterner<B, H>
+where
+    B: ContiguousBackend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    /// Returns the string of the symbol with the given `usize` value if any.
+    ///
+    /// Returns `None` if the string has been [removed](`StringInterner::remove`).
+    ///
+    /// # Note
+    ///
+    /// This is equivalent to resolving the symbol constructed from `index`
+    /// but avoids the conversion for callers that store symbols as raw indices.
+    /// See [`Symbol::to_usize`] for the `usize` value of a symbol.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::{DefaultStringInterner, Symbol};
+    /// let mut interner = <DefaultStringInterner>::new();
+    /// let index = interner.get_or_intern("Tiger").to_usize();
+    /// assert_eq!(interner.resolve_index(index), Some("Tiger"));
+    /// assert_eq!(interner.resolve_index(index + 1), None);
+    /// ```
+    #[inline]
+    pub fn resolve_index(&self, index: usize) -> Option<&str> {
+        if !self.tombstones.is_empty() && self.tombstones.contains(&index) {
+            return None;
+        }
+        self.backend.resolve_index(index)
+    }
+
+    /// Returns an iterator over the symbols with the values `0..n` where `n` is
+    /// the number of strings ever interned since the last [clear](`StringInterner::clear`).
+    ///
+    /// Symbols of strings that have been [removed](`StringInterner::remove`) are skipped.
+    ///
+    /// # Note
+    ///
+    /// Unlike [`StringInterner::iter_symbols`] the symbols are computed from
+    /// their values only without accessing the backend. This allows to build
+    /// side tables that are indexed by symbols.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let interner = <DefaultStringInterner>::from_iter(["Tiger", "Horse"]);
+    /// let lengths = interner
+    ///     .symbols()
+    ///     .map(|symbol| interner.resolve(symbol).map_or(0, str::len))
+    ///     .collect::<Vec<_>>();
+    /// assert_eq!(lengths, [5, 5]);
+    /// ```
+    #[inline]
+    pub fn symbols(&self) -> SymbolRange<'_, <B as Backend>::Symbol> {
+        SymbolRange {
+            range: 0..self.slot_count(),
+            tombstones: &self.tombstones,
+            remaining: self.len(),
+            marker: PhantomData,
+        }
+    }
+
+    /// Returns a view of the interner that resolves strings by the `usize` values of their symbols.
+    ///
+    /// # Note
+    ///
+    /// The returned [`ByIndex`] implements `Index<usize>` which allows to
+    /// resolve strings by raw indices without constructing symbols.
+    /// The valid indices are `0..len` if no strings have been
+    /// [removed](`StringInterner::remove`).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// # use string_interner::DefaultStringInterner;
+    /// let interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+    /// let strings = interner.by_index();
+    /// assert_eq!(&strings[1], "b");
+    /// assert_eq!(strings.get(3), None);
+    /// ```
+    #[inline]
+    pub fn by_index(&self) -> ByIndex<'_, B, H> {
+        ByIndex { interner: self }
+    }
+}
+
+impl<B, H, T> FromIterator<T> for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default,
+    T: AsRef<str>,
+{
+    fn from_iter<I>(iter: I) -> Self
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let (capacity, _) = iter.size_hint();
+        let mut interner = Self::with_capacity(capacity);
+        interner.extend(iter);
+        interner
+    }
+}
+
+impl<B, H, T> Extend<T> for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+    T: AsRef<str>,
+{
+    fn extend<I>(&mut self, iter: I)
+    where
+        I: IntoIterator<Item = T>,
+    {
+        let iter = iter.into_iter();
+        let (additional, _) = iter.size_hint();
+        let Self {
+            dedup,
+            hasher,
+            backend,
+            ..
+        } = self;
+        // Only the deduplication table is reserved since the backend may
+        // already have been sized precisely, e.g. via `with_capacity_for`.
+        // Reserving is only an optimization so failures are deferred to interning.
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        let _ = unsafe { dedup.try_reserve(hasher, backend, additional) };
+        for s in iter {
+            self.get_or_intern(s.as_ref());
+        }
+    }
+}
+
+impl<B, H> Index<<B as Backend>::Symbol> for StringInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    type Output = str;
+
+    /// Returns the string for the given `symbol`.
+    ///
+    /// # Panics
+    ///
+    /// If `symbol` does not refer to a string of the interner.
+    #[inline]
+    #[track_caller]
+    fn index(&self, symbol: <B as Backend>::Symbol) -> &Self::Output {
+        self.resolve(symbol)
+            .expect("encountered invalid symbol for string interner")
+    }
+}
+
+/// Iterator resolving symbols of a [`StringInterner`] into their strings.
+///
+/// Created by [`StringInterner::resolve_iter`].
+pub struct ResolveIter<'a, B, H, I>
+where
+    B: Backend,
+{
+    interner: &'a StringInterner<B, H>,
+    symbols: I,
+}
+
+impl<'a, B, H, I> Iterator for ResolveIter<'a, B, H, I>
+where
+    B: Backend,
+    H: BuildHasher,
+    I: Iterator<Item = <B as Backend>::Symbol>,
+{
+    type Item = Option<&'a str>;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.symbols.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.symbols
+            .next()
+            .map(|symbol| self.interner.resolve(symbol))
+    }
+}
+
+impl<'a, B, H, I> DoubleEndedIterator for ResolveIter<'a, B, H, I>
+where
+    B: Backend,
+    H: BuildHasher,
+    I: DoubleEndedIterator<Item = <B as Backend>::Symbol>,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.symbols
+            .next_back()
+            .map(|symbol| self.interner.resolve(symbol))
+    }
+}
+
+impl<'a, B, H, I> ExactSizeIterator for ResolveIter<'a, B, H, I>
+where
+    B: Backend,
+    H: BuildHasher,
+    I: ExactSizeIterator<Item = <B as Backend>::Symbol>,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.symbols.len()
+    }
+}
+
+impl<'a, B, H, I> FusedIterator for ResolveIter<'a, B, H, I>
+where
+    B: Backend,
+    H: BuildHasher,
+    I: FusedIterator<Item = <B as Backend>::Symbol>,
+{
+}
+
+/// View of a [`StringInterner`] resolving strings by the `usize` values of their symbols.
+///
+/// Created by [`StringInterner::by_index`].
+pub struct ByIndex<'a, B, H = DefaultHashBuilder>
+where
+    B: Backend,
+{
+    interner: &'a StringInterner<B, H>,
+}
+
+impl<'a, B, H> Clone for ByIndex<'a, B, H>
+where
+    B: Backend,
+{
+    #[inline]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<'a, B, H> Copy for ByIndex<'a, B, H> where B: Backend {}
+
+impl<'a, B, H> ByIndex<'a, B, H>
+where
+    B: ContiguousBackend,
+    H: BuildHasher,
+{
+    /// Returns the string of the symbol with the given `index` if any.
+    #[inline]
+    pub fn get(&self, index: usize) -> Option<&'a str> {
+        self.interner.resolve_index(index)
+    }
+}
+
+impl<'a, B, H> Index<usize> for ByIndex<'a, B, H>
+where
+    B: ContiguousBackend,
+    H: BuildHasher,
+{
+    type Output = str;
+
+    /// Returns the string of the symbol with the given `index`.
+    ///
+    /// # Panics
+    ///
+    /// If `index` does not refer to a string of the interner.
+    #[inline]
+    #[track_caller]
+    fn index(&self, index: usize) -> &Self::Output {
+        self.get(index)
+            .expect("encountered invalid index for string interner")
+    }
+}
+
+impl<'a, B, H> IntoIterator for &'a StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    type Item = (<B as Backend>::Symbol, &'a str);
+    type IntoIter = Iter<'a, B>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
+
+/// Converts the interner into an iterator over the owned interned strings and their symbols.
+///
+/// # Note
+///
+/// The entries are yielded in the same order and with the same symbols as by
+/// [`StringInterner::iter`], i.e. in ascending order of the `usize` values of
+/// their symbols. Strings that have been [removed](`StringInterner::remove`) are skipped.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::DefaultStringInterner;
+/// let interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+/// let a = interner.get("a").unwrap();
+/// let entries = interner.into_iter().collect::<Vec<_>>();
+/// assert_eq!(entries[0], (a, String::from("a")));
+/// ```
+impl<B, H> IntoIterator for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher,
+{
+    type Item = (<B as Backend>::Symbol, String);
+    type IntoIter = IntoIter<B>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        let symbols = self.iter_symbols().collect::<Vec<_>>();
+        IntoIter {
+            backend: self.backend,
+            symbols: symbols.into_iter(),
+        }
+    }
+}
+
+/// Iterator over the interned strings of a [`StringInterner`] and their symbols.
+///
+/// Skips over strings that have been [removed](`StringInterner::remove`).
+pub struct Iter<'a, B>
+where
+    B: Backend + 'a,
+{
+    iter: <B as Backend>::Iter<'a>,
+    tombstones: &'a HashSet<usize>,
+    remaining: usize,
+}
+
+impl<'a, B> Iter<'a, B>
+where
+    B: Backend + 'a,
+{
+    /// Creates an iterator over the `len` live strings of `backend`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub(crate) fn new(backend: &'a B, tombstones: &'a HashSet<usize>, len: usize) -> Self {
+        Self {
+            iter: backend.iter(),
+            tombstones,
+            remaining: len,
+        }
+    }
+}
+
+impl<'a, B> Iterator for Iter<'a, B>
+where
+    B: Backend + 'a,
+{
+    type Item = (<B as Backend>::Symbol, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let tombstones = self.tombstones;
+        let item = if tombstones.is_empty() {
+            self.iter.next()?
+        } else {
+            self.iter
+                .find(|(symbol, _)| !tombstones.contains(&symbol.to_usize()))?
+        };
+        self.remaining -= 1;
+        Some(item)
+    }
+}
+
+impl<'a, B> DoubleEndedIterator for Iter<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let tombstones = self.tombstones;
+        let item = if tombstones.is_empty() {
+            self.iter.next_back()?
+        } else {
+            self.iter
+                .rfind(|(symbol, _)| !tombstones.contains(&symbol.to_usize()))?
+        };
+        self.remaining -= 1;
+        Some(item)
+    }
+}
+
+impl<'a, B> ExactSizeIterator for Iter<'a, B>
+where
+    B: Backend + 'a,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, B> FusedIterator for Iter<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: FusedIterator,
+{
+}
+
+/// Iterator over the interned strings of a [`StringInterner`] within a range of symbols.
+///
+/// Created by [`StringInterner::symbols_in_range`] and [`StringInterner::iter_from`].
+pub struct IterRange<'a, B>
+where
+    B: Backend + 'a,
+{
+    iter: <B as Backend>::Iter<'a>,
+    tombstones: &'a HashSet<usize>,
+    start: usize,
+    end: usize,
+}
+
+impl<'a, B> Iterator for IterRange<'a, B>
+where
+    B: Backend + 'a,
+{
+    type Item = (<B as Backend>::Symbol, &'a str);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        if self.start < self.end {
+            (0, self.iter.size_hint().1)
+        } else {
+            (0, Some(0))
+        }
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        while self.start < self.end {
+            let (symbol, string) = self.iter.next()?;
+            let index = symbol.to_usize();
+            if index >= self.end {
+                // Symbols are ascending so no further symbols are within the range.
+                self.start = self.end;
+                return None;
+            }
+            if index >= self.start
+                && (self.tombstones.is_empty() || !self.tombstones.contains(&index))
+            {
+                return Some((symbol, string));
+            }
+        }
+        None
+    }
+}
+
+impl<'a, B> FusedIterator for IterRange<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: FusedIterator,
+{
+}
+
+/// Iterator over the symbols of the interned strings of a [`StringInterner`].
+///
+/// Skips over strings that have been [removed](`StringInterner::remove`).
+pub struct Symbols<'a, B>
+where
+    B: Backend + 'a,
+{
+    iter: Iter<'a, B>,
+}
+
+impl<'a, B> Iterator for Symbols<'a, B>
+where
+    B: Backend + 'a,
+{
+    type Item = <B as Backend>::Symbol;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(symbol, _)| symbol)
+    }
+}
+
+impl<'a, B> DoubleEndedIterator for Symbols<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(symbol, _)| symbol)
+    }
+}
+
+impl<'a, B> ExactSizeIterator for Symbols<'a, B>
+where
+    B: Backend + 'a,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, B> FusedIterator for Symbols<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: FusedIterator,
+{
+}
+
+/// Iterator over the UTF-8 bytes of the interned strings of a [`StringInterner`].
+///
+/// Created by [`StringInterner::iter_bytes`].
+/// Skips over strings that have been [removed](`StringInterner::remove`).
+pub struct IterBytes<'a, B>
+where
+    B: Backend + 'a,
+{
+    iter: Iter<'a, B>,
+}
+
+impl<'a, B> Iterator for IterBytes<'a, B>
+where
+    B: Backend + 'a,
+{
+    type Item = (<B as Backend>::Symbol, &'a [u8]);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next()
+            .map(|(symbol, string)| (symbol, string.as_bytes()))
+    }
+}
+
+impl<'a, B> DoubleEndedIterator for IterBytes<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter
+            .next_back()
+            .map(|(symbol, string)| (symbol, string.as_bytes()))
+    }
+}
+
+impl<'a, B> ExactSizeIterator for IterBytes<'a, B>
+where
+    B: Backend + 'a,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<'a, B> FusedIterator for IterBytes<'a, B>
+where
+    B: Backend + 'a,
+    <B as Backend>::Iter<'a>: FusedIterator,
+{
+}
+
+/// Iterator over the symbols of the interned strings of a [`StringInterner`] by their values.
+///
+/// Skips over strings that have been [removed](`StringInterner::remove`).
+///
+/// Created by [`StringInterner::symbols`].
+#[derive(Debug, Clone)]
+pub struct SymbolRange<'a, S> {
+    range: Range<usize>,
+    tombstones: &'a HashSet<usize>,
+    remaining: usize,
+    marker: PhantomData<fn() -> S>,
+}
+
+impl<'a, S> Iterator for SymbolRange<'a, S>
+where
+    S: Symbol,
+{
+    type Item = S;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        (self.remaining, Some(self.remaining))
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let tombstones = self.tombstones;
+        let index = if tombstones.is_empty() {
+            self.range.next()?
+        } else {
+            self.range.find(|index| !tombstones.contains(index))?
+        };
+        self.remaining -= 1;
+        Some(S::from_usize(index))
+    }
+}
+
+impl<'a, S> DoubleEndedIterator for SymbolRange<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let tombstones = self.tombstones;
+        let index = if tombstones.is_empty() {
+            self.range.next_back()?
+        } else {
+            self.range.rfind(|index| !tombstones.contains(index))?
+        };
+        self.remaining -= 1;
+        Some(S::from_usize(index))
+    }
+}
+
+impl<'a, S> ExactSizeIterator for SymbolRange<'a, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.remaining
+    }
+}
+
+impl<'a, S> FusedIterator for SymbolRange<'a, S> where S: Symbol {}
+
+/// A snapshot of the number of strings interned by a [`StringInterner`].
+///
+/// Created by [`StringInterner::checkpoint`] and consumed by [`StringInterner::rollback`].
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct Checkpoint {
+    /// The number of strings, including removed ones, held by the backend.
+    len: usize,
+    /// The number of times the interner had been cleared.
+    generation: usize,
+}
+
+/// The entry of a string in a [`StringInterner`].
+///
+/// Created by [`StringInterner::entry`].
+pub enum Entry<'a, B, H = DefaultHashBuilder>
+where
+    B: Backend,
+{
+    /// The string has already been interned with the contained symbol.
+    Occupied(<B as Backend>::Symbol),
+    /// The string has not been interned yet.
+    Vacant(VacantEntry<'a, B, H>),
+}
+
+impl<'a, B, H> Entry<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    /// Returns the symbol of the entry, interning its string if it is vacant.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn or_intern(self) -> <B as Backend>::Symbol {
+        match self {
+            Self::Occupied(symbol) => symbol,
+            Self::Vacant(vacant) => vacant.intern(),
+        }
+    }
+}
+
+/// The entry of a string that has not been interned yet.
+///
+/// Part of the [`Entry`] enum.
+pub struct VacantEntry<'a, B, H = DefaultHashBuilder>
+where
+    B: Backend,
+{
+    entry: RawVacantEntryMut<'a, <B as Backend>::Symbol, (), ()>,
+    hasher: &'a H,
+    backend: &'a mut B,
+    string: &'a str,
+    hash: u64,
+    max_str_len: usize,
+    #[cfg(feature = "stats")]
+    stats: &'a mut InternStats,
+    #[cfg(feature = "observer")]
+    observer: &'a Observer<<B as Backend>::Symbol>,
+}
+
+impl<'a, B, H> VacantEntry<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    /// Returns the string of the entry.
+    #[inline]
+    pub fn key(&self) -> &'a str {
+        self.string
+    }
+
+    /// Interns the string of the entry and returns its symbol.
+    ///
+    /// # Panics
+    ///
+    /// - If the interner already interns the maximum number of strings possible
+    ///   by the chosen symbol type.
+    /// - If the string exceeds the
+    ///   [maximum string length](`StringInterner::set_max_str_len`) of the interner.
+    #[inline]
+    pub fn intern(self) -> <B as Backend>::Symbol {
+        let Self {
+            entry,
+            hasher,
+            backend,
+            string,
+            hash,
+            max_str_len,
+            #[cfg(feature = "stats")]
+            stats,
+            #[cfg(feature = "observer")]
+            observer,
+        } = self;
+        assert!(
+            string.len() <= max_str_len,
+            "failed to intern string: {}",
+            InternError::TooLong
+        );
+        let symbol = backend.intern(string);
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        unsafe { Dedup::insert_vacant(entry, hasher, &*backend, hash, symbol) };
+        #[cfg(feature = "stats")]
+        stats.record(string.len(), true);
+        #[cfg(feature = "observer")]
+        observer.notify(symbol, string.len(), true);
+        symbol
+    }
+}
+
+/// Iterator over the drained strings of a [`StringInterner`] and their symbols.
+///
+/// Created by [`StringInterner::drain`].
+///
+/// The interner is [cleared](`StringInterner::clear`) when the iterator is dropped.
+pub struct Drain<'a, B, H = DefaultHashBuilder>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    /// Iterates over the strings of the drained interner.
+    ///
+    /// This is only `None` while the iterator is dropped.
+    iter: Option<Iter<'a, B>>,
+    /// The exclusively borrowed interner that is cleared when the iterator is dropped.
+    interner: NonNull<StringInterner<B, H>>,
+    marker: PhantomData<&'a mut StringInterner<B, H>>,
+}
+
+// SAFETY: The `Drain` behaves like an exclusive borrow of the interner
+//         together with a shared iterator over its strings.
+unsafe impl<'a, B, H> Send for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+    StringInterner<B, H>: Send,
+    Iter<'a, B>: Send,
+{
+}
+
+// SAFETY: The `Drain` behaves like an exclusive borrow of the interner
+//         together with a shared iterator over its strings.
+unsafe impl<'a, B, H> Sync for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+    StringInterner<B, H>: Sync,
+    Iter<'a, B>: Sync,
+{
+}
+
+impl<'a, B, H> Drop for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    fn drop(&mut self) {
+        // Release the shared borrows of the interner before clearing it.
+        self.iter = None;
+        // SAFETY: The interner is exclusively borrowed by the `Drain` and
+        //         no longer shared since the iterator has been dropped.
+        unsafe { self.interner.as_mut() }.clear();
+    }
+}
+
+impl<'a, B, H> Iterator for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    type Item = (<B as Backend>::Symbol, String);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.as_ref().map_or((0, Some(0)), Iterator::size_hint)
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        let (symbol, string) = self.iter.as_mut()?.next()?;
+        Some((symbol, String::from(string)))
+    }
+}
+
+impl<'a, B, H> DoubleEndedIterator for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+    <B as Backend>::Iter<'a>: DoubleEndedIterator,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let (symbol, string) = self.iter.as_mut()?.next_back()?;
+        Some((symbol, String::from(string)))
+    }
+}
+
+impl<'a, B, H> ExactSizeIterator for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.as_ref().map_or(0, ExactSizeIterator::len)
+    }
+}
+
+impl<'a, B, H> FusedIterator for Drain<'a, B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+    <B as Backend>::Iter<'a>: FusedIterator,
+{
+}
+
+/// Iterator over the owned interned strings of a [`StringInterner`] and their symbols.
+///
+/// Created by the [`IntoIterator`] implementation of [`StringInterner`].
+pub struct IntoIter<B>
+where
+    B: Backend,
+{
+    backend: B,
+    /// The symbols of the remaining strings in the order of their interning.
+    symbols: alloc::vec::IntoIter<<B as Backend>::Symbol>,
+}
+
+impl<B> IntoIter<B>
+where
+    B: Backend,
+{
+    /// Returns the given `symbol` together with a copy of its string.
+    #[inline]
+    fn entry(&self, symbol: <B as Backend>::Symbol) -> (<B as Backend>::Symbol, String) {
+        // SAFETY: This is safe because we only operate on symbols that
+        //         we receive from our backend making them valid.
+        let string = unsafe { self.backend.resolve_unchecked(symbol) };
+        (symbol, String::from(string))
+    }
+}
+
+impl<B> Iterator for IntoIter<B>
+where
+    B: Backend,
+{
+    type Item = (<B as Backend>::Symbol, String);
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.symbols.size_hint()
     }
 
-    /// Returns an iterator that yields all interned strings and their symbols.
     #[inline]
-    pub fn iter(&self) -> <B as Backend>::Iter<'_> {
-        self.backend.iter()
+    fn next(&mut self) -> Option<Self::Item> {
+        let symbol = self.symbols.next()?;
+        Some(self.entry(symbol))
     }
 }
 
-impl<B, H, T> FromIterator<T> for StringInterner<B, H>
+impl<B> DoubleEndedIterator for IntoIter<B>
 where
     B: Backend,
-    <B as Backend>::Symbol: Symbol,
-    H: BuildHasher + Default,
-    T: AsRef<str>,
 {
-    fn from_iter<I>(iter: I) -> Self
-    where
-        I: IntoIterator<Item = T>,
-    {
-        let iter = iter.into_iter();
-        let (capacity, _) = iter.size_hint();
-        let mut interner = Self::with_capacity(capacity);
-        interner.extend(iter);
-        interner
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        let symbol = self.symbols.next_back()?;
+        Some(self.entry(symbol))
     }
 }
 
-impl<B, H, T> Extend<T> for StringInterner<B, H>
+impl<B> ExactSizeIterator for IntoIter<B>
 where
     B: Backend,
-    <B as Backend>::Symbol: Symbol,
-    H: BuildHasher,
-    T: AsRef<str>,
 {
-    fn extend<I>(&mut self, iter: I)
-    where
-        I: IntoIterator<Item = T>,
-    {
-        for s in iter {
-            self.get_or_intern(s.as_ref());
-        }
+    #[inline]
+    fn len(&self) -> usize {
+        self.symbols.len()
     }
 }
 
-impl<'a, B, H> IntoIterator for &'a StringInterner<B, H>
+impl<B> FusedIterator for IntoIter<B> where B: Backend {}
+
+/// Iterator over the owned interned strings of a [`StringInterner`].
+///
+/// Created by [`StringInterner::into_values`].
+pub struct IntoValues<B>
 where
     B: Backend,
-    <B as Backend>::Symbol: Symbol,
-    &'a B: IntoIterator<Item = (<B as Backend>::Symbol, &'a str)>,
-    H: BuildHasher,
 {
-    type Item = (<B as Backend>::Symbol, &'a str);
-    type IntoIter = <&'a B as IntoIterator>::IntoIter;
+    iter: IntoIter<B>,
+}
 
-    #[cfg_attr(feature = "inline-more", inline)]
-    fn into_iter(self) -> Self::IntoIter {
-        self.backend.into_iter()
+impl<B> Iterator for IntoValues<B>
+where
+    B: Backend,
+{
+    type Item = String;
+
+    #[inline]
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.iter.size_hint()
+    }
+
+    #[inline]
+    fn next(&mut self) -> Option<Self::Item> {
+        self.iter.next().map(|(_, string)| string)
+    }
+}
+
+impl<B> DoubleEndedIterator for IntoValues<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn next_back(&mut self) -> Option<Self::Item> {
+        self.iter.next_back().map(|(_, string)| string)
+    }
+}
+
+impl<B> ExactSizeIterator for IntoValues<B>
+where
+    B: Backend,
+{
+    #[inline]
+    fn len(&self) -> usize {
+        self.iter.len()
+    }
+}
+
+impl<B> FusedIterator for IntoValues<B> where B: Backend {}
+
+#[cfg(all(test, feature = "backends"))]
+mod tests {
+    use crate::{
+        backend::{
+            ArcBackend, Backend, BucketBackend, BufferBackend, ContiguousBackend, InlineBackend,
+            StringBackend, SubstringBackend,
+        },
+        symbol::{RawSymbolU32, SymbolU16, SymbolU8},
+        DefaultStringInterner, Entry, InternError, StringInterner, Symbol,
+    };
+    use alloc::{
+        borrow::Cow,
+        format,
+        string::{String, ToString},
+        vec::Vec,
+    };
+    use core::cmp::Ordering;
+
+    /// Runs the generic test function `$check` for every backend of this crate.
+    ///
+    /// With `contiguous` only the backends implementing [`ContiguousBackend`] are used.
+    macro_rules! for_each_backend {
+        (contiguous $check:ident) => {
+            $check::<ArcBackend>();
+            $check::<BucketBackend>();
+            $check::<InlineBackend>();
+            $check::<StringBackend>();
+            $check::<SubstringBackend>();
+        };
+        ($check:ident) => {
+            for_each_backend!(contiguous $check);
+            $check::<BufferBackend>();
+        };
+    }
+
+    #[test]
+    fn remove_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        let c = interner.get_or_intern("c");
+        assert_eq!(interner.remove(b), Some(String::from("b")));
+        assert_eq!(interner.remove(b), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(a), Some("a"));
+        assert_eq!(interner.resolve(b), None);
+        assert_eq!(interner.resolve(c), Some("c"));
+        assert_eq!(interner.get("b"), None);
+        assert_eq!(interner.iter().collect::<Vec<_>>(), [(a, "a"), (c, "c")]);
+        let b2 = interner.get_or_intern("b");
+        assert_ne!(b, b2);
+        assert_eq!(b2.to_usize(), 3);
+        assert_eq!(interner.len(), 3);
+    }
+
+    #[test]
+    fn extract_if_works() {
+        fn check<B: Backend>() {
+            let mut interner = StringInterner::<B>::from_iter(["$a", "b", "$c", "d", "$e"]);
+            let e = interner.get("$e").unwrap();
+            assert_eq!(interner.remove(e).as_deref(), Some("$e"));
+            let b = interner.get("b").unwrap();
+            let extracted = interner
+                .extract_if(|_, string| string.starts_with('$'))
+                .map(|(symbol, string)| (symbol.to_usize(), string))
+                .collect::<Vec<_>>();
+            let strings = extracted.iter().map(|(_, string)| string.as_str());
+            assert!(strings.eq(["$a", "$c"]));
+            assert!(extracted.windows(2).all(|pair| pair[0].0 < pair[1].0));
+            assert_eq!(interner.len(), 2);
+            assert!(interner.get("$a").is_none());
+            assert_eq!(interner.resolve(b), Some("b"));
+            let strings = interner.iter().map(|(_, string)| string);
+            assert!(strings.eq(["b", "d"]));
+            assert_eq!(interner.extract_if(|_, _| false).count(), 0);
+        }
+        for_each_backend!(check);
+    }
+
+    fn assert_double_ended_iter<B>()
+    where
+        B: Backend,
+        for<'a> <B as Backend>::Iter<'a>: DoubleEndedIterator,
+    {
+        let mut interner = StringInterner::<B>::from_iter(["a", "b", "c", "d", "e"]);
+        let removed = interner.get("c").unwrap();
+        interner.remove(removed);
+        let entries = |iter: &mut dyn Iterator<Item = (B::Symbol, &str)>| {
+            iter.map(|(symbol, string)| (symbol.to_usize(), String::from(string)))
+                .collect::<Vec<_>>()
+        };
+        let forward = entries(&mut interner.iter());
+        let mut backward = entries(&mut interner.iter().rev());
+        backward.reverse();
+        assert_eq!(forward, backward);
+        assert_eq!(forward.len(), 4);
+        let mut iter = interner.iter().map(|(_, string)| string);
+        assert_eq!(iter.next(), Some("a"));
+        assert_eq!(iter.next_back(), Some("e"));
+        assert_eq!(iter.next_back(), Some("d"));
+        assert_eq!(iter.next(), Some("b"));
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.next_back(), None);
+    }
+
+    #[test]
+    fn double_ended_iter_works() {
+        for_each_backend!(contiguous assert_double_ended_iter);
+    }
+
+    #[test]
+    fn exact_size_iter_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        assert_eq!(interner.iter().len(), 3);
+        interner.remove(interner.get("b").unwrap());
+        let mut iter = interner.iter();
+        assert_eq!(iter.len(), 2);
+        iter.next();
+        assert_eq!(iter.len(), 1);
+        iter.next();
+        assert_eq!(iter.len(), 0);
+        assert_eq!(iter.next(), None);
+        assert_eq!(iter.len(), 0);
+    }
+
+    #[test]
+    fn drain_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        interner.remove(interner.get("b").unwrap());
+        let drained = interner
+            .drain()
+            .map(|(symbol, string)| (symbol.to_usize(), string))
+            .collect::<Vec<_>>();
+        assert_eq!(drained, [(0, String::from("a")), (2, String::from("c"))]);
+        assert!(interner.is_empty());
+        // Dropping a partially consumed `Drain` clears the interner, too.
+        interner.extend(["x", "y"]);
+        assert_eq!(
+            interner.drain().next().map(|(_, string)| string).as_deref(),
+            Some("x")
+        );
+        assert!(interner.is_empty());
+        assert_eq!(interner.get("a"), None);
+        let d = interner.get_or_intern("d");
+        assert_eq!(d.to_usize(), 0);
+        assert_eq!(interner.resolve(d), Some("d"));
+    }
+
+    #[test]
+    fn index_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let a = interner.get_or_intern("a");
+        assert_eq!(&interner[a], "a");
+    }
+
+    #[test]
+    #[should_panic]
+    fn index_removed_panics() {
+        let mut interner = <DefaultStringInterner>::new();
+        let a = interner.get_or_intern("a");
+        interner.remove(a);
+        let _ = &interner[a];
+    }
+
+    #[test]
+    fn memory_usage_works() {
+        fn assert_memory_usage<B: Backend>() {
+            let mut interner = StringInterner::<B>::new();
+            assert_eq!(interner.total_string_bytes(), 0);
+            interner.extend(["foo", "ba", "foo", ""]);
+            assert_eq!(interner.total_string_bytes(), 5);
+            assert!(interner.memory_usage() >= interner.total_string_bytes());
+            assert!(interner.byte_capacity() >= interner.total_string_bytes());
+        }
+        for_each_backend!(assert_memory_usage);
+    }
+
+    #[test]
+    fn resolve_many_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        let a = interner.get("a").unwrap();
+        let b = interner.get("b").unwrap();
+        interner.remove(b);
+        assert_eq!(
+            interner.resolve_many([a, b, a]),
+            [Some("a"), None, Some("a")]
+        );
+        assert_eq!(interner.resolve_all([a, a]), Some(["a", "a"]));
+        assert_eq!(interner.resolve_all([a, b]), None);
+        assert_eq!(interner.resolve_all([]), Some([]));
+    }
+
+    #[test]
+    fn hash_works() {
+        use core::hash::BuildHasher as _;
+        let hasher = hashbrown::hash_map::DefaultHashBuilder::default();
+        let a = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let b = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let c = <DefaultStringInterner>::from_iter(["b", "a"]);
+        assert_eq!(hasher.hash_one(&a), hasher.hash_one(&b));
+        assert_ne!(hasher.hash_one(&a), hasher.hash_one(&c));
+    }
+
+    #[test]
+    fn eq_unordered_works() {
+        let mut a = <DefaultStringInterner>::with_capacity(100);
+        a.extend(["a", "b", "c"]);
+        let mut b = <DefaultStringInterner>::from_iter(["c", "x", "b", "a"]);
+        assert!(!a.eq_unordered(&b));
+        b.remove(b.get("x").unwrap());
+        assert!(a.eq_unordered(&b));
+        assert!(b.eq_unordered(&a));
+        assert!(a != b);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "encountered invalid symbol for resolver")]
+    fn resolver_resolve_unchecked_panics_in_debug() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let b = interner.get("b").unwrap();
+        interner.remove(b);
+        let resolver = interner.into_resolver();
+        unsafe { resolver.resolve_unchecked(b) };
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "encountered invalid symbol for string interner")]
+    fn resolve_unchecked_panics_in_debug() {
+        let interner = <DefaultStringInterner>::from_iter(["a"]);
+        unsafe { interner.resolve_unchecked(crate::DefaultSymbol::from_usize(1)) };
+    }
+
+    #[test]
+    #[cfg(all(unix, feature = "std"))]
+    fn get_or_intern_os_works() {
+        use std::{ffi::OsStr, os::unix::ffi::OsStrExt, path::Path};
+        let mut interner = <DefaultStringInterner>::new();
+        let path = interner.get_or_intern_os(Path::new("src/lib.rs")).unwrap();
+        assert_eq!(interner.resolve(path), Some("src/lib.rs"));
+        assert_eq!(interner.get_or_intern_os(OsStr::from_bytes(b"\xFF")), None);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn clone_preserves_symbols() {
+        fn assert_clone_preserves_symbols<B: Backend + Clone>() {
+            let mut interner = StringInterner::<B>::new();
+            let symbols = interner.intern_all(["a", "bb", "", "ccc"]);
+            interner.remove(symbols[1]);
+            let cloned = interner.clone();
+            for &symbol in &symbols {
+                assert_eq!(interner.resolve(symbol), cloned.resolve(symbol));
+            }
+            for (string, &symbol) in ["a", "bb", "", "ccc"].iter().zip(&symbols) {
+                assert!(interner.get(string) == cloned.get(string));
+                assert_eq!(cloned.resolve(symbol).is_some(), *string != "bb");
+            }
+        }
+        for_each_backend!(assert_clone_preserves_symbols);
+    }
+
+    #[test]
+    fn iter_order_works() {
+        fn symbol_values<B: Backend>() -> Vec<usize> {
+            let interner = StringInterner::<B>::from_iter(["c", "a", "bb", "a", ""]);
+            assert!(interner
+                .iter()
+                .map(|(_, string)| string)
+                .eq(["c", "a", "bb", ""]));
+            interner
+                .iter()
+                .map(|(symbol, _)| symbol.to_usize())
+                .collect()
+        }
+        assert!(symbol_values::<StringBackend>().into_iter().eq(0..4));
+        assert!(symbol_values::<BucketBackend>().into_iter().eq(0..4));
+        let values = symbol_values::<BufferBackend>();
+        assert!(values.windows(2).all(|pair| pair[0] < pair[1]));
+    }
+
+    #[test]
+    fn get_or_intern_string_types_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let owned = String::from("a");
+        let a = interner.get_or_intern("a");
+        assert_eq!(interner.get_or_intern(&owned), a);
+        assert_eq!(interner.get_or_intern(owned.clone()), a);
+        assert_eq!(interner.get_or_intern(Cow::Borrowed("a")), a);
+        assert_eq!(interner.get_or_intern(Cow::<str>::Owned(owned)), a);
+        assert_eq!(interner.len(), 1);
+    }
+
+    #[test]
+    fn entry_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let a = interner.get_or_intern("a");
+        assert!(matches!(interner.entry("a"), Entry::Occupied(symbol) if symbol == a));
+        let b = match interner.entry("b") {
+            Entry::Occupied(_) => panic!("expected vacant entry"),
+            Entry::Vacant(vacant) => {
+                assert_eq!(vacant.key(), "b");
+                vacant.intern()
+            }
+        };
+        assert_eq!(interner.get("b"), Some(b));
+        assert_eq!(interner.entry("b").or_intern(), b);
+        // Dropping a vacant entry leaves the interner unchanged.
+        assert!(matches!(interner.entry("c"), Entry::Vacant(_)));
+        assert_eq!(interner.get("c"), None);
+        let c = interner.entry("c").or_intern();
+        assert_eq!(interner.resolve(c), Some("c"));
+        assert_eq!(interner.len(), 3);
+    }
+
+    #[test]
+    fn shrink_to_works() {
+        fn check<B>()
+        where
+            B: Backend,
+        {
+            let mut interner = StringInterner::<B>::with_capacity(1000);
+            interner.extend(["a", "bb", "ccc"]);
+            let before = interner.memory_usage();
+            let dedup_before = interner.hashmap_capacity();
+            interner.shrink_to(100);
+            let after = interner.memory_usage();
+            let dedup_after = interner.hashmap_capacity();
+            assert!(after < before);
+            assert!(dedup_after < dedup_before);
+            assert!(dedup_after >= 100);
+            interner.shrink_to_fit();
+            assert!(interner.memory_usage() <= after);
+            assert!(interner.hashmap_capacity() < dedup_after);
+            assert!(interner.hashmap_capacity() >= interner.len());
+            assert!(interner.byte_capacity() >= interner.total_string_bytes());
+            assert!(interner
+                .iter()
+                .map(|(_, string)| string)
+                .eq(["a", "bb", "ccc"]));
+        }
+        for_each_backend!(check);
+    }
+
+    #[test]
+    fn resolve_iter_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        interner.remove(b);
+        let symbols = [a, b, a];
+        let mut strings = interner.resolve_iter(symbols);
+        assert_eq!(strings.len(), 3);
+        assert_eq!(strings.next_back(), Some(Some("a")));
+        assert_eq!(strings.next(), Some(Some("a")));
+        assert_eq!(strings.next(), Some(None));
+        assert_eq!(strings.next(), None);
+    }
+
+    #[test]
+    fn send_sync_works() {
+        fn assert_send_sync<T: Send + Sync>() {}
+        assert_send_sync::<StringInterner<StringBackend>>();
+        assert_send_sync::<StringInterner<BufferBackend>>();
+        assert_send_sync::<StringInterner<BucketBackend>>();
+        assert_send_sync::<StringInterner<SubstringBackend>>();
+    }
+
+    #[test]
+    fn eq_across_hashers_works() {
+        use core::hash::{BuildHasherDefault, Hasher};
+
+        #[derive(Default)]
+        struct FnvHasher(u64);
+
+        impl Hasher for FnvHasher {
+            fn finish(&self) -> u64 {
+                self.0
+            }
+
+            fn write(&mut self, bytes: &[u8]) {
+                for &byte in bytes {
+                    self.0 = (self.0 ^ u64::from(byte)).wrapping_mul(0x100_0000_01b3);
+                }
+            }
+        }
+
+        type OtherHasher = BuildHasherDefault<FnvHasher>;
+        let lhs = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let mut rhs = StringInterner::<StringBackend, OtherHasher>::from_iter(["a", "b"]);
+        assert!(lhs == rhs);
+        rhs.get_or_intern("c");
+        assert!(lhs != rhs);
+    }
+
+    #[test]
+    fn with_capacity_for_works() {
+        fn check<B>()
+        where
+            B: Backend,
+        {
+            let strings = ["Tiger", "Horse", "Lion", "Tiger"];
+            let bytes = strings.iter().map(|s| s.len()).sum();
+            let mut interner = StringInterner::<B>::with_capacity_for(strings.len(), bytes);
+            let capacity = interner.byte_capacity();
+            assert!(capacity >= bytes);
+            interner.extend(strings);
+            assert_eq!(interner.byte_capacity(), capacity);
+            assert_eq!(interner.len(), 3);
+        }
+        // Only these backends preallocate a buffer for the string contents.
+        check::<BucketBackend>();
+        check::<BufferBackend>();
+        check::<StringBackend>();
+        check::<SubstringBackend>();
+    }
+
+    #[test]
+    fn get_or_intern_with_works() {
+        let mut interner = StringInterner::<BucketBackend>::new();
+        let mut calls = 0;
+        let mut make = |string: &str| {
+            calls += 1;
+            String::from(string)
+        };
+        let a = interner.get_or_intern_with("a", || make("a"));
+        assert_eq!(interner.get_or_intern_with("a", || make("a")), a);
+        let b = interner.get_or_intern_with("b", || make("b"));
+        assert_eq!(calls, 2);
+        assert_eq!(interner.resolve(a), Some("a"));
+        assert_eq!(interner.get("b"), Some(b));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_or_intern_with_invalid_make_panics() {
+        let mut interner = <DefaultStringInterner>::new();
+        interner.get_or_intern_with("a", || String::from("b"));
+    }
+
+    #[test]
+    fn max_str_len_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let long = interner.get_or_intern("long");
+        assert_eq!(interner.max_str_len(), None);
+        interner.set_max_str_len(Some(3));
+        assert_eq!(interner.max_str_len(), Some(3));
+        assert_eq!(
+            interner.try_get_or_intern("longer"),
+            Err(InternError::TooLong)
+        );
+        assert_eq!(interner.try_get_or_intern("abc").map(|_| ()), Ok(()));
+        assert_eq!(interner.try_get_or_intern("long"), Ok(long));
+        assert_eq!(interner.get_or_intern("long"), long);
+        assert_eq!(interner.resolve(long), Some("long"));
+        assert_eq!(interner.len(), 2);
+        interner.set_max_str_len(None);
+        assert!(interner.try_get_or_intern("longer").is_ok());
+    }
+
+    #[test]
+    #[should_panic]
+    fn max_str_len_get_or_intern_panics() {
+        let mut interner = <DefaultStringInterner>::new();
+        interner.set_max_str_len(Some(3));
+        interner.get_or_intern("long");
+    }
+
+    #[test]
+    fn debug_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["foo", "bar", "baz"]);
+        interner.remove(interner.get("bar").unwrap());
+        assert_eq!(format!("{interner:?}"), r#"{0: "foo", 2: "baz"}"#);
+        assert_eq!(format!("{:?}", <DefaultStringInterner>::new()), "{}");
+    }
+
+    #[test]
+    fn symbols_in_range_works() {
+        fn check<B>()
+        where
+            B: Backend,
+        {
+            let mut interner = StringInterner::<B>::new();
+            let symbols = ["a", "bb", "ccc", "dddd", "eeeee"].map(|s| interner.get_or_intern(s));
+            interner.remove(symbols[2]);
+            let range = |start: usize, end: usize| {
+                interner
+                    .symbols_in_range(symbols[start]..symbols[end])
+                    .map(|(_, string)| string)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(range(1, 4), ["bb", "dddd"]);
+            assert_eq!(range(0, 1), ["a"]);
+            assert!(range(3, 3).is_empty());
+            assert!(range(4, 1).is_empty());
+        }
+        for_each_backend!(check);
+    }
+
+    #[test]
+    fn backend_iter_from_works() {
+        fn check<B: Backend>() {
+            let mut backend = B::default();
+            for string in ["a", "bb", "", "ccc", "dddd"] {
+                backend.intern(string);
+            }
+            let last = backend.iter().last().unwrap().0.to_usize();
+            for start in 0..=last + 2 {
+                let expected = backend
+                    .iter()
+                    .filter(|(symbol, _)| symbol.to_usize() >= start)
+                    .map(|(symbol, string)| (symbol.to_usize(), string))
+                    .collect::<Vec<_>>();
+                let actual = backend
+                    .iter_from(start)
+                    .map(|(symbol, string)| (symbol.to_usize(), string))
+                    .collect::<Vec<_>>();
+                assert_eq!(actual, expected);
+            }
+        }
+        for_each_backend!(check);
+    }
+
+    #[test]
+    fn iter_from_works() {
+        fn check<B>()
+        where
+            B: Backend,
+        {
+            let mut interner = StringInterner::<B>::new();
+            let symbols = ["a", "bb", "ccc", "dddd"].map(|s| interner.get_or_intern(s));
+            interner.remove(symbols[2]);
+            let from = |start: usize| {
+                interner
+                    .iter_from(symbols[start])
+                    .map(|(_, string)| string)
+                    .collect::<Vec<_>>()
+            };
+            assert_eq!(from(0), ["a", "bb", "dddd"]);
+            assert_eq!(from(1), ["bb", "dddd"]);
+            assert_eq!(from(2), ["dddd"]);
+            assert_eq!(from(3), ["dddd"]);
+        }
+        for_each_backend!(check);
+    }
+
+    #[test]
+    fn rollback_works() {
+        fn check<B>()
+        where
+            B: Backend,
+        {
+            let mut interner = StringInterner::<B>::new();
+            let a = interner.get_or_intern("a");
+            let bb = interner.get_or_intern("bb");
+            interner.remove(bb);
+            let checkpoint = interner.checkpoint();
+            let bytes = interner.total_string_bytes();
+            let ccc = interner.get_or_intern("ccc");
+            interner.get_or_intern("dddd");
+            interner.remove(ccc);
+            interner.rollback(checkpoint);
+            assert_eq!(interner.len(), 1);
+            assert_eq!(interner.total_string_bytes(), bytes);
+            assert!(interner.get("ccc").is_none());
+            assert!(interner.get("dddd").is_none());
+            assert_eq!(interner.resolve(a), Some("a"));
+            assert_eq!(interner.resolve(bb), None);
+            assert!(interner.get_or_intern("ccc") == ccc);
+            assert_eq!(interner.resolve(ccc), Some("ccc"));
+            // Rolling back to the same checkpoint again removes the new string.
+            interner.rollback(checkpoint);
+            assert!(interner.iter().map(|(_, string)| string).eq(["a"]));
+            interner.clear();
+            interner.rollback(checkpoint);
+            assert!(interner.is_empty());
+            // Checkpoints created before a clear are ignored.
+            interner.extend(["a", "b", "c", "d"]);
+            interner.rollback(checkpoint);
+            assert_eq!(interner.len(), 4);
+        }
+        for_each_backend!(check);
+    }
+
+    #[test]
+    fn first_last_symbol_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        assert!(interner.first_symbol().is_none());
+        assert!(interner.last_symbol().is_none());
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        let c = interner.get_or_intern("c");
+        assert_eq!(interner.first_symbol(), Some(a));
+        assert_eq!(interner.last_symbol(), Some(c));
+        interner.remove(a);
+        interner.remove(c);
+        assert_eq!(interner.first_symbol(), Some(b));
+        assert_eq!(interner.last_symbol(), Some(b));
+    }
+
+    #[test]
+    fn into_values_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        interner.remove(interner.get("b").unwrap());
+        let mut values = interner.into_values();
+        assert_eq!(values.len(), 2);
+        assert_eq!(values.next_back().as_deref(), Some("c"));
+        assert_eq!(values.next().as_deref(), Some("a"));
+        assert_eq!(values.next(), None);
+    }
+
+    #[test]
+    fn get_or_intern_hashed_across_shards_works() {
+        use core::hash::BuildHasher;
+        let hasher = hashbrown::hash_map::DefaultHashBuilder::default();
+        let mut shards =
+            [0, 1].map(|_| StringInterner::<StringBackend, _>::with_hasher(hasher.clone()));
+        for string in ["a", "b", "a"] {
+            let hash = hasher.hash_one(string);
+            assert_eq!(hash, shards[0].hash_string(string));
+            for shard in &mut shards {
+                let symbol = shard.get_or_intern_hashed(string, hash);
+                assert_eq!(shard.get(string), Some(symbol));
+            }
+        }
+        assert!(shards.iter().all(|shard| shard.len() == 2));
+    }
+
+    #[test]
+    fn with_capacity_constructors_reserve_dedup() {
+        let hasher = hashbrown::hash_map::DefaultHashBuilder::default();
+        let plain = <DefaultStringInterner>::with_capacity(100);
+        let hashed = StringInterner::<StringBackend, _>::with_capacity_and_hasher(100, hasher);
+        assert!(plain.hashmap_capacity() >= 100);
+        assert!(hashed.hashmap_capacity() >= 100);
+        assert_eq!(plain.byte_capacity(), hashed.byte_capacity());
+    }
+
+    #[test]
+    fn symbols_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        let expected = interner.iter_symbols().collect::<Vec<_>>();
+        assert!(interner.symbols().collect::<Vec<_>>() == expected);
+        assert_eq!(interner.symbols().len(), 3);
+        interner.remove(expected[1]);
+        assert!(interner.symbols().collect::<Vec<_>>() == [expected[0], expected[2]]);
+        assert!(interner.symbols().rev().collect::<Vec<_>>() == [expected[2], expected[0]]);
+        assert_eq!(interner.symbols().len(), 2);
+        interner.clear();
+        assert_eq!(interner.symbols().next(), None);
+    }
+
+    fn iter_and_into_iter_agree_for<B>()
+    where
+        B: Backend,
+    {
+        let mut interner = StringInterner::<B>::from_iter(["a", "b", "c", "b", "d"]);
+        interner.remove(interner.get("c").unwrap());
+        let borrowed = interner
+            .iter()
+            .map(|(symbol, string)| (symbol.to_usize(), String::from(string)))
+            .collect::<Vec<_>>();
+        let len = interner.iter().len();
+        let owned = interner.into_iter();
+        assert_eq!(owned.len(), len);
+        let owned = owned
+            .map(|(symbol, string)| (symbol.to_usize(), string))
+            .collect::<Vec<_>>();
+        assert_eq!(borrowed, owned);
+        assert!(owned.windows(2).all(|pair| pair[0].0 < pair[1].0));
+        assert_eq!(
+            owned.iter().map(|(_, string)| string).collect::<Vec<_>>(),
+            ["a", "b", "d"]
+        );
+    }
+
+    #[test]
+    fn iter_and_into_iter_agree() {
+        for_each_backend!(iter_and_into_iter_agree_for);
+    }
+
+    fn resolve_index_works_for<B>()
+    where
+        B: ContiguousBackend,
+    {
+        let mut interner = StringInterner::<B>::from_iter(["a", "bb", "ccc"]);
+        let entries = interner
+            .iter()
+            .map(|(symbol, string)| (symbol.to_usize(), String::from(string)))
+            .collect::<Vec<_>>();
+        for (index, string) in &entries {
+            assert_eq!(interner.resolve_index(*index), Some(string.as_str()));
+        }
+        assert_eq!(interner.resolve_index(usize::MAX), None);
+        interner.remove(interner.get("bb").unwrap());
+        assert_eq!(interner.resolve_index(entries[1].0), None);
+        assert_eq!(interner.resolve_index(entries[2].0), Some("ccc"));
+    }
+
+    #[test]
+    fn resolve_index_works() {
+        for_each_backend!(contiguous resolve_index_works_for);
+    }
+
+    #[test]
+    fn push_unique_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let symbols = ["a", "b", "c"].map(|string| interner.push_unique(string));
+        let expected = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        assert!(interner == expected);
+        for (symbol, string) in symbols.into_iter().zip(["a", "b", "c"]) {
+            assert!(interner.get(string) == Some(symbol));
+        }
+        assert!(interner.get_or_intern("b") == symbols[1]);
+        assert_eq!(interner.len(), 3);
+    }
+
+    #[test]
+    #[cfg(debug_assertions)]
+    #[should_panic(expected = "encountered string that has already been interned")]
+    fn push_unique_duplicate_panics() {
+        let mut interner = <DefaultStringInterner>::new();
+        interner.push_unique("a");
+        interner.push_unique("a");
+    }
+
+    #[test]
+    #[cfg(all(feature = "observer", feature = "std"))]
+    fn intern_observer_works() {
+        use crate::InternEvent;
+        use alloc::sync::Arc;
+        use std::sync::Mutex;
+
+        let events = Arc::new(Mutex::new(Vec::new()));
+        let mut interner = <DefaultStringInterner>::new();
+        let recorded = Arc::clone(&events);
+        interner.set_intern_observer(move |event| recorded.lock().unwrap().push(event));
+        let a = interner.get_or_intern("a");
+        interner.get_or_intern("a");
+        let bb = interner.entry("bb").or_intern();
+        interner.entry("bb").or_intern();
+        let ccc = interner.push_unique("ccc");
+        interner.get("a");
+        interner.clear_intern_observer();
+        interner.get_or_intern("d");
+        assert!(
+            *events.lock().unwrap()
+                == [
+                    InternEvent::Miss(a, 1),
+                    InternEvent::Hit(a),
+                    InternEvent::Miss(bb, 2),
+                    InternEvent::Hit(bb),
+                    InternEvent::Miss(ccc, 3),
+                ]
+        );
+    }
+
+    #[test]
+    fn clone_with_hasher_works() {
+        use core::hash::BuildHasher;
+
+        #[derive(Default)]
+        struct NoCloneHasher(hashbrown::hash_map::DefaultHashBuilder);
+
+        impl BuildHasher for NoCloneHasher {
+            type Hasher = <hashbrown::hash_map::DefaultHashBuilder as BuildHasher>::Hasher;
+
+            fn build_hasher(&self) -> Self::Hasher {
+                self.0.build_hasher()
+            }
+        }
+
+        let mut interner = StringInterner::<StringBackend, NoCloneHasher>::new();
+        interner.extend(["a", "b", "c"]);
+        let b = interner.get("b").unwrap();
+        interner.remove(b);
+        let mut clone = interner.clone_with_hasher(NoCloneHasher::default());
+        assert!(clone == interner);
+        for (symbol, string) in &interner {
+            assert!(clone.get(string) == Some(symbol));
+        }
+        assert!(clone.resolve(b).is_none());
+        let d = clone.get_or_intern("d");
+        assert!(interner.get_or_intern("d") == d);
+    }
+
+    #[test]
+    fn len_excludes_removed_strings() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        assert_eq!((interner.len(), interner.slot_count()), (3, 3));
+        let b = interner.get("b").unwrap();
+        interner.remove(b);
+        assert_eq!((interner.len(), interner.slot_count()), (2, 3));
+        assert_eq!(interner.iter().len(), interner.len());
+        interner.get_or_intern("b");
+        assert_eq!((interner.len(), interner.slot_count()), (3, 4));
+        let symbols = interner.iter_symbols().collect::<Vec<_>>();
+        for symbol in symbols {
+            interner.remove(symbol);
+        }
+        assert!(interner.is_empty());
+        assert_eq!(interner.slot_count(), 4);
+        interner.clear();
+        assert_eq!((interner.len(), interner.slot_count()), (0, 0));
+    }
+
+    #[test]
+    fn ord_works() {
+        let ab = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let ac = <DefaultStringInterner>::from_iter(["a", "c"]);
+        let a = <DefaultStringInterner>::from_iter(["a"]);
+        assert_eq!(ab.cmp(&ab.clone()), Ordering::Equal);
+        assert_eq!(a.cmp(&ab), Ordering::Less);
+        assert_eq!(ab.cmp(&ac), Ordering::Less);
+        assert_eq!(ac.cmp(&a), Ordering::Greater);
+        let mut removed = ab.clone();
+        removed.remove(removed.get("b").unwrap());
+        assert!(removed != ab);
+        assert_eq!(ab.cmp(&removed), Ordering::Less);
+        let mut sorted = [ac.clone(), removed.clone(), a.clone(), ab.clone()];
+        sorted.sort();
+        assert!(sorted == [a, ab, removed, ac]);
+    }
+
+    #[test]
+    fn get_borrowed_key_works() {
+        use core::borrow::Borrow;
+
+        /// Key type that only implements `Borrow<str>`.
+        struct Key(String);
+
+        impl Borrow<str> for Key {
+            fn borrow(&self) -> &str {
+                &self.0
+            }
+        }
+
+        let mut interner = <DefaultStringInterner>::new();
+        let key = Key(String::from("Tiger"));
+        let tiger = interner.get_or_intern(Borrow::<str>::borrow(&key));
+        assert_eq!(interner.get(Borrow::<str>::borrow(&key)), Some(tiger));
+        let horse = Key(String::from("Horse"));
+        assert_eq!(interner.get(Borrow::<str>::borrow(&horse)), None);
+    }
+
+    #[test]
+    fn get_all_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        let names = ["b", "c", "a"].map(String::from);
+        let symbols = interner.get_all(&names).collect::<Vec<_>>();
+        assert_eq!(symbols, [Some(b), None, Some(a)]);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.get_all(Vec::<&str>::new()).count(), 0);
+    }
+
+    #[test]
+    fn from_iter_with_capacity_works() {
+        let strings = (0..100).map(|i| i.to_string()).filter(|_| true);
+        assert_eq!(strings.size_hint().0, 0);
+        let interner = <DefaultStringInterner>::from_iter_with_capacity(strings, 100);
+        assert_eq!(interner.len(), 100);
+        assert!(interner.hashmap_capacity() >= 100);
+        let capacity = interner.hashmap_capacity();
+        let mut interner = <DefaultStringInterner>::new();
+        interner.extend((0..capacity).map(|i| i.to_string()));
+        assert_eq!(interner.hashmap_capacity(), capacity);
+        let interner = <DefaultStringInterner>::from_iter_with_capacity(["a", "b", "a"], 0);
+        assert_eq!(interner.len(), 2);
+    }
+
+    #[test]
+    fn bytes_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["Tiger", "Ärger", "Horse"]);
+        let tiger = interner.get("Tiger").unwrap();
+        let horse = interner.get("Horse").unwrap();
+        assert_eq!(interner.resolve_bytes(tiger), Some(&b"Tiger"[..]));
+        interner.remove(horse);
+        assert_eq!(interner.resolve_bytes(horse), None);
+        let bytes = interner.iter_bytes().map(|(_, bytes)| bytes);
+        assert!(bytes.eq([&b"Tiger"[..], "Ärger".as_bytes()]));
+        assert_eq!(interner.iter_bytes().len(), 2);
+    }
+
+    #[test]
+    fn clear_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        interner.remove(interner.get("b").unwrap());
+        interner.clear();
+        assert!(interner.is_empty());
+        assert_eq!(interner.iter().next(), None);
+        assert_eq!(interner.get("a"), None);
+        let c = interner.get_or_intern("c");
+        assert_eq!(c.to_usize(), 0);
+        assert_eq!(interner.resolve(c), Some("c"));
+    }
+
+    #[test]
+    fn into_resolver_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        let b = interner.get("b").unwrap();
+        interner.remove(b);
+        let expected = interner
+            .iter()
+            .map(|(symbol, string)| (symbol, String::from(string)))
+            .collect::<Vec<_>>();
+        let resolver = interner.into_resolver();
+        assert_eq!(resolver.len(), 2);
+        assert_eq!(resolver.resolve(b), None);
+        for (symbol, string) in &expected {
+            assert_eq!(resolver.resolve(*symbol), Some(string.as_str()));
+        }
+        assert!(resolver
+            .iter()
+            .map(|(symbol, _)| symbol)
+            .eq(expected.iter().map(|(symbol, _)| *symbol)));
+    }
+
+    #[test]
+    fn try_reserve_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        assert_eq!(interner.try_reserve(100), Ok(()));
+        assert!(interner.try_reserve(usize::MAX).is_err());
+        let a = interner.get_or_intern("a");
+        assert_eq!(interner.try_reserve(1000), Ok(()));
+        assert_eq!(interner.get("a"), Some(a));
+    }
+
+    #[test]
+    fn try_reserve_bytes_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        assert_eq!(interner.try_reserve(2), Ok(()));
+        assert_eq!(interner.try_reserve_bytes(10), Ok(()));
+        let capacity = interner.byte_capacity();
+        assert!(capacity >= 10);
+        interner.extend(["Tiger", "Horse"]);
+        assert_eq!(interner.byte_capacity(), capacity);
+        assert!(interner.try_reserve_bytes(usize::MAX).is_err());
+        assert_eq!(interner.byte_capacity(), capacity);
+        let mut interner = StringInterner::<BucketBackend>::new();
+        assert_eq!(interner.try_reserve_bytes(usize::MAX), Ok(()));
+    }
+
+    #[test]
+    fn try_get_or_intern_works() {
+        let mut interner = StringInterner::<StringBackend<SymbolU16>>::new();
+        for i in 0..usize::from(u16::MAX) {
+            interner.get_or_intern(i.to_string());
+        }
+        let first = interner.get("0").unwrap();
+        assert_eq!(interner.try_get_or_intern("0"), Ok(first));
+        assert_eq!(
+            interner.try_get_or_intern("new"),
+            Err(InternError::OutOfSymbols)
+        );
+        assert_eq!(interner.len(), usize::from(u16::MAX));
+        assert_eq!(interner.iter().len(), usize::from(u16::MAX));
+        assert_eq!(interner.get("new"), None);
+    }
+
+    #[test]
+    fn raw_symbol_works() {
+        let mut interner = StringInterner::<StringBackend<RawSymbolU32>>::new();
+        let a = interner.get_or_intern("a");
+        let b = interner.get_or_intern("b");
+        assert_eq!(a.to_raw(), 0);
+        assert_eq!(b.to_raw(), 1);
+        assert_eq!(interner.resolve(RawSymbolU32::from_raw(1)), Some("b"));
+        assert_eq!(interner.resolve(RawSymbolU32::from_raw(2)), None);
+    }
+
+    #[test]
+    fn get_or_intern_at_symbol_limit_works() {
+        let mut interner = StringInterner::<StringBackend<SymbolU8>>::new();
+        let symbols = (0..u8::MAX)
+            .map(|i| interner.get_or_intern(i.to_string()))
+            .collect::<Vec<_>>();
+        assert_eq!(interner.len(), usize::from(u8::MAX));
+        for (i, &symbol) in symbols.iter().enumerate() {
+            assert_eq!(interner.resolve(symbol), Some(i.to_string().as_str()));
+        }
+        assert_eq!(
+            interner.try_get_or_intern("new"),
+            Err(InternError::OutOfSymbols)
+        );
+        assert_eq!(interner.resolve(symbols[0]), Some("0"));
+    }
+
+    #[test]
+    #[should_panic]
+    fn get_or_intern_past_symbol_limit_panics() {
+        let mut interner = StringInterner::<StringBackend<SymbolU8>>::new();
+        for i in 0..=u8::MAX {
+            interner.get_or_intern(i.to_string());
+        }
+    }
+
+    #[test]
+    fn contains_symbol_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let a = interner.get("a").unwrap();
+        let b = interner.get("b").unwrap();
+        assert!(interner.contains_symbol(a));
+        assert!(interner.contains_symbol(b));
+        assert!(!interner.contains_symbol(Symbol::from_usize(2)));
+        interner.remove(a);
+        assert!(!interner.contains_symbol(a));
+        assert!(interner.contains_symbol(b));
+    }
+
+    #[test]
+    fn iter_symbols_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b", "c"]);
+        interner.remove(interner.get("b").unwrap());
+        let symbols = interner.iter_symbols().collect::<Vec<_>>();
+        assert_eq!(
+            symbols,
+            [interner.get("a").unwrap(), interner.get("c").unwrap()]
+        );
+        assert_eq!(interner.iter_symbols().len(), 2);
+    }
+
+    #[test]
+    fn merge_works() {
+        let mut interner = <DefaultStringInterner>::from_iter(["a", "b"]);
+        let other = StringInterner::<BucketBackend>::from_iter(["c", "b", "d"]);
+        let other_symbols = ["c", "b", "d"].map(|string| other.get(string).unwrap());
+        let remap = interner.merge(other);
+        assert_eq!(interner.len(), 4);
+        let expected = ["c", "b", "d"].map(|string| interner.get(string).unwrap());
+        assert_eq!(
+            remap,
+            other_symbols.into_iter().zip(expected).collect::<Vec<_>>()
+        );
+        assert_eq!(remap[1].1.to_usize(), 1);
+    }
+
+    #[test]
+    fn get_or_intern_full_works() {
+        let mut interner = <DefaultStringInterner>::new();
+        let (a, new_a) = interner.get_or_intern_full("a");
+        let (b, new_b) = interner.get_or_intern_full("b");
+        assert!(new_a);
+        assert!(new_b);
+        assert_eq!(interner.get_or_intern_full("a"), (a, false));
+        assert_eq!(interner.get_or_intern_full("b"), (b, false));
+        assert_eq!(interner.len(), 2);
+    }
+}
+
+/// Tests for the defaulted type parameters of the [`StringInterner`].
+///
+/// These must pass with and without the `std` feature, e.g. via
+/// `cargo test --no-default-features --features backends`.
+#[cfg(all(test, feature = "backends"))]
+mod defaulted_tests {
+    use crate::{backend::StringBackend, symbol::SymbolU32, StringInterner};
+
+    #[test]
+    fn defaulted_hasher_works() {
+        let mut interner = StringInterner::<StringBackend<SymbolU32>>::new();
+        let a = interner.get_or_intern("a");
+        assert_eq!(interner.get("a"), Some(a));
+        assert_eq!(interner.resolve(a), Some("a"));
+        let defaulted: StringInterner<StringBackend<SymbolU32>> = StringInterner::default();
+        assert!(defaulted.is_empty());
     }
 }
@@ -9,9 +9,9 @@
 //! ### Example: Interning & Symbols
 //!
 //! ```
-//! use string_interner::StringInterner;
+//! use string_interner::DefaultStringInterner;
 //!
-//! let mut interner = StringInterner::default();
+//! let mut interner = <DefaultStringInterner>::default();
 //! let sym0 = interner.get_or_intern("Elephant");
 //! let sym1 = interner.get_or_intern("Tiger");
 //! let sym2 = interner.get_or_intern("Horse");
@@ -34,8 +34,8 @@
 //! ### Example: Look-up
 //!
 //! ```
-//! # use string_interner::StringInterner;
-//! let mut interner = StringInterner::default();
+//! # use string_interner::DefaultStringInterner;
+//! let mut interner = <DefaultStringInterner>::default();
 //! let sym = interner.get_or_intern("Banana");
 //! assert_eq!(interner.resolve(sym), Some("Banana"));
 //! ```
@@ -91,14 +91,14 @@
 //! - **Contiguous:** True if the returned symbols have contiguous values.
 //! - **Iteration:** Efficiency of iterating over the interned strings.
 //!
-//! | **Property** | **BucketBackend** | **StringBackend** | **BufferBackend** |
-//! |:-------------|:-----------------:|:-----------------:|:-----------------:|
-//! | **Fill**     | ok                | good              | best              |
-//! | **Resolve**  | best              | good              | bad               |
-//! | Allocations  | ok                | good              | best              |
-//! | Footprint    | ok                | good              | best              |
-//! | Contiguous   | yes               | yes               | no                |
-//! | Iteration    | best              | good              | bad               |
+//! | **Property** | **BucketBackend** | **StringBackend** | **BufferBackend** | **SubstringBackend** | **InlineBackend** | **ArcBackend** |
+//! |:-------------|:-----------------:|:-----------------:|:-----------------:|:--------------------:|:-----------------:|:--------------:|
+//! | **Fill**     | ok                | good              | best              | bad                  | good              | ok             |
+//! | **Resolve**  | best              | good              | bad               | good                 | good              | best           |
+//! | Allocations  | ok                | good              | best              | good                 | good              | bad            |
+//! | Footprint    | ok                | good              | best              | best                 | ok                | ok             |
+//! | Contiguous   | yes               | yes               | no                | yes                  | yes               | yes            |
+//! | Iteration    | best              | good              | bad               | good                 | good              | good           |
 //!
 //! ## When to use which backend?
 //!
@@ -118,6 +118,31 @@
 //! Some operations are even slightly more efficient and it consumes less memory.
 //! However, all this is at the costs of a less efficient resolution of symbols.
 //! Note that the symbols generated by the `BufferBackend` are not contiguous.
+//!
+//! ### Substring Backend
+//!
+//! The `SubstringBackend` stores strings that are contained in previously interned
+//! strings only once and therefore has the smallest footprint for such strings.
+//! However, filling it is quadratic in the total length of the interned strings
+//! which makes it only suitable for small sets of strings.
+//!
+//! ### Inline Backend
+//!
+//! The `InlineBackend` stores short strings inline and only allocates for longer
+//! ones. It is a good fit for workloads that mostly intern short identifiers.
+//!
+//! ### Arc Backend
+//!
+//! The `ArcBackend` allocates every string separately in an `Arc<str>`.
+//! This allows to share interned strings beyond the lifetime of the interner
+//! at the cost of one allocation per interned string.
+//!
+//! ## `no_std` Support
+//!
+//! The crate supports `no_std` environments that provide an allocator by
+//! disabling the `std` crate feature. Deduplication is always hash based via
+//! [`hashbrown`] so `no_std` users benefit from the same `O(1)` look-ups and
+//! may plug in any [`BuildHasher`](`core::hash::BuildHasher`) of their choice.
 
 #[cfg(not(feature = "std"))]
 extern crate alloc;
@@ -127,23 +152,88 @@ extern crate std as alloc;
 #[cfg(feature = "serde-1")]
 mod serde_impl;
 
+#[cfg(feature = "rayon")]
+mod rayon_impl;
+
 pub mod backend;
+pub mod borrowed;
+mod builder;
+pub mod bytes;
+#[cfg(feature = "backends")]
+mod case_insensitive;
+mod dedup;
+pub mod error;
 mod interner;
+#[cfg(feature = "backends")]
+mod normalized;
+#[cfg(feature = "observer")]
+mod observer;
+mod resolver;
+mod shared;
+mod stats;
 pub mod symbol;
 
 /// A convenience [`StringInterner`] type based on the [`DefaultBackend`].
+///
+/// Uses the [`DefaultHashBuilder`] which is based on the `aHash` hashing algorithm.
 #[cfg(feature = "backends")]
 pub type DefaultStringInterner<B = DefaultBackend, H = DefaultHashBuilder> =
     self::interner::StringInterner<B, H>;
 
+/// A convenience [`StringInterner`] type based on the [`DefaultBackend`] and `FxHash`.
+///
+/// # Note
+///
+/// `FxHash` is very fast for short strings but is not resistant against HashDoS attacks.
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::FxStringInterner;
+/// let mut interner = <FxStringInterner>::new();
+/// let sym = interner.get_or_intern("Banana");
+/// assert_eq!(interner.resolve(sym), Some("Banana"));
+/// ```
+#[cfg(all(feature = "backends", feature = "fxhash"))]
+pub type FxStringInterner<B = DefaultBackend> =
+    self::interner::StringInterner<B, fxhash::FxBuildHasher>;
+
 #[cfg(feature = "backends")]
 #[doc(inline)]
 pub use self::backend::DefaultBackend;
+#[cfg(feature = "backends")]
+#[doc(inline)]
+pub use self::case_insensitive::CaseInsensitiveInterner;
+#[cfg(feature = "backends")]
+#[doc(inline)]
+pub use self::normalized::NormalizedInterner;
+#[cfg(feature = "observer")]
+#[doc(inline)]
+pub use self::observer::InternEvent;
+#[cfg(feature = "sync")]
+#[doc(inline)]
+pub use self::shared::SharedInterner;
+#[cfg(feature = "stats")]
+#[doc(inline)]
+pub use self::stats::InternStats;
 #[doc(inline)]
 pub use self::{
-    interner::StringInterner,
+    borrowed::BorrowedInterner,
+    builder::StringInternerBuilder,
+    bytes::ByteInterner,
+    error::{InternError, TryReserveError},
+    interner::{
+        ByIndex, Checkpoint, Drain, Entry, IntoIter, IntoValues, Iter, IterBytes, IterRange,
+        ResolveIter, StringInterner, SymbolRange, Symbols, VacantEntry,
+    },
+    resolver::Resolver,
     symbol::{DefaultSymbol, Symbol},
 };
 
+/// The default hasher of the [`StringInterner`].
+///
+/// It is provided by `hashbrown` and is also available in `no_std` environments.
+/// Therefore the defaulted hasher parameter, e.g. in `StringInterner<B>`, works
+/// without the `std` feature and no separate `no_std` hasher is required.
 #[doc(inline)]
 pub use hashbrown::hash_map::DefaultHashBuilder;
@@ -0,0 +1,219 @@
+use crate::{backend::Backend, Symbol};
+use alloc::{borrow::Cow, boxed::Box};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+};
+use hashbrown::{
+    hash_map::{DefaultHashBuilder, RawEntryMut},
+    HashMap,
+};
+
+/// Data structure to intern and resolve strings deduplicated by a normalized form.
+///
+/// Strings with equal normalized forms are associated to the same symbol.
+/// Symbols resolve to the original spelling of the string that has been interned first.
+///
+/// # Note
+///
+/// The normalized forms are owned by the deduplication table whereas the
+/// backend only stores the original strings. Therefore the contents of every
+/// interned string are stored twice: as original spelling and normalized form.
+///
+/// The normalization is provided per call, e.g. Unicode NFC normalization
+/// via the `unicode-normalization` crate. It is a logic error to use
+/// different normalizations on the same interner.
+///
+/// # Example
+///
+/// ```
+/// # use std::borrow::Cow;
+/// # use string_interner::NormalizedInterner;
+/// // Stand-in for Unicode normalization: collapses runs of whitespace.
+/// fn normalize(string: &str) -> Cow<'_, str> {
+///     string.split_whitespace().collect::<Vec<_>>().join(" ").into()
+/// }
+/// let mut interner = <NormalizedInterner>::new();
+/// let sym0 = interner.get_or_intern_normalized("hello  world", normalize);
+/// let sym1 = interner.get_or_intern_normalized("hello world", normalize);
+/// assert_eq!(sym0, sym1);
+/// assert_eq!(interner.resolve(sym1), Some("hello  world"));
+/// ```
+pub struct NormalizedInterner<B = crate::DefaultBackend, H = DefaultHashBuilder>
+where
+    B: Backend,
+{
+    dedup: HashMap<Box<str>, <B as Backend>::Symbol, H>,
+    backend: B,
+}
+
+impl<B, H> Debug for NormalizedInterner<B, H>
+where
+    B: Backend + Debug,
+    <B as Backend>::Symbol: Symbol + Debug,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("NormalizedInterner")
+            .field("dedup", &self.dedup)
+            .field("backend", &self.backend)
+            .finish()
+    }
+}
+
+impl<B, H> Default for NormalizedInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<B, H> Clone for NormalizedInterner<B, H>
+where
+    B: Backend + Clone,
+    H: BuildHasher + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            dedup: self.dedup.clone(),
+            backend: self.backend.clone(),
+        }
+    }
+}
+
+impl<B, H> NormalizedInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `NormalizedInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<B, H> NormalizedInterner<B, H>
+where
+    B: Backend,
+    H: BuildHasher,
+{
+    /// Creates a new empty `NormalizedInterner` with the given hasher.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: HashMap::with_hasher(hash_builder),
+            backend: B::default(),
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.dedup.len()
+    }
+
+    /// Returns `true` if the string interner has no interned strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the symbol for the given string by its normalized form if any.
+    #[inline]
+    pub fn get_normalized<T, F>(&self, string: T, normalize: F) -> Option<<B as Backend>::Symbol>
+    where
+        T: AsRef<str>,
+        F: FnOnce(&str) -> Cow<str>,
+    {
+        let normalized = normalize(string.as_ref());
+        self.dedup.get(&*normalized).copied()
+    }
+
+    /// Interns the given string deduplicated by its normalized form.
+    ///
+    /// Returns a symbol for resolution into the first interned spelling of the string.
+    ///
+    /// # Note
+    ///
+    /// `normalize` is called exactly once. The normalized form is only copied
+    /// if the string has not been interned before and `normalize` borrowed it.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    #[inline]
+    pub fn get_or_intern_normalized<T, F>(
+        &mut self,
+        string: T,
+        normalize: F,
+    ) -> <B as Backend>::Symbol
+    where
+        T: AsRef<str>,
+        F: FnOnce(&str) -> Cow<str>,
+    {
+        let string = string.as_ref();
+        let normalized = normalize(string);
+        let Self { dedup, backend } = self;
+        match dedup.raw_entry_mut().from_key(&*normalized) {
+            RawEntryMut::Occupied(occupied) => *occupied.get(),
+            RawEntryMut::Vacant(vacant) => {
+                let symbol = backend.intern(string);
+                vacant.insert(Box::from(normalized), symbol);
+                symbol
+            }
+        }
+    }
+
+    /// Returns the first interned spelling of the string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        self.backend.resolve(symbol)
+    }
+
+    /// Returns an iterator that yields all interned strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> <B as Backend>::Iter<'_> {
+        self.backend.iter()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use alloc::{string::String, vec::Vec};
+
+    fn fold_case(string: &str) -> Cow<'_, str> {
+        if string.bytes().any(|byte| byte.is_ascii_uppercase()) {
+            Cow::Owned(string.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(string)
+        }
+    }
+
+    #[test]
+    fn normalized_works() {
+        let mut interner = <NormalizedInterner>::new();
+        let a = interner.get_or_intern_normalized("Foo", fold_case);
+        let b = interner.get_or_intern_normalized("bar", fold_case);
+        assert_eq!(interner.get_or_intern_normalized("foo", fold_case), a);
+        let bar = String::from("BAR");
+        assert_eq!(interner.get_or_intern_normalized(bar, fold_case), b);
+        assert_eq!(interner.get_normalized("FOO", fold_case), Some(a));
+        assert_eq!(interner.get_normalized("baz", fold_case), None);
+        assert_eq!(interner.len(), 2);
+        assert_eq!(interner.resolve(a), Some("Foo"));
+        assert_eq!(interner.resolve(b), Some("bar"));
+        let strings = interner
+            .iter()
+            .map(|(_, string)| string)
+            .collect::<Vec<_>>();
+        assert_eq!(strings, ["Foo", "bar"]);
+    }
+}
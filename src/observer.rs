@@ -0,0 +1,64 @@
+#![cfg(feature = "observer")]
+
+use alloc::sync::Arc;
+use core::{fmt, fmt::Debug};
+
+/// An intern request observed by the observer of a [`StringInterner`](`crate::StringInterner`).
+///
+/// See [`StringInterner::set_intern_observer`](`crate::StringInterner::set_intern_observer`).
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum InternEvent<S> {
+    /// The string has been interned before and its existing symbol is returned.
+    Hit(S),
+    /// The string has been newly interned with the given symbol and length in bytes.
+    Miss(S, usize),
+}
+
+/// The optional observer of intern requests.
+pub(crate) struct Observer<S> {
+    callback: Option<Arc<dyn Fn(InternEvent<S>) + Send + Sync>>,
+}
+
+impl<S> Default for Observer<S> {
+    #[inline]
+    fn default() -> Self {
+        Self { callback: None }
+    }
+}
+
+impl<S> Clone for Observer<S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self {
+            callback: self.callback.clone(),
+        }
+    }
+}
+
+impl<S> Debug for Observer<S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Observer")
+            .field("is_set", &self.callback.is_some())
+            .finish()
+    }
+}
+
+impl<S> Observer<S> {
+    /// Replaces the callback of the observer.
+    #[inline]
+    pub(crate) fn set(&mut self, callback: Option<Arc<dyn Fn(InternEvent<S>) + Send + Sync>>) {
+        self.callback = callback;
+    }
+
+    /// Notifies the callback of the observer about an intern request if any.
+    #[inline]
+    pub(crate) fn notify(&self, symbol: S, len: usize, is_new: bool) {
+        if let Some(callback) = &self.callback {
+            if is_new {
+                callback(InternEvent::Miss(symbol, len));
+            } else {
+                callback(InternEvent::Hit(symbol));
+            }
+        }
+    }
+}
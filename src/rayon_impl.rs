@@ -0,0 +1,78 @@
+use crate::{backend::Backend, dedup::make_hash, StringInterner, Symbol};
+use alloc::vec::Vec;
+use core::hash::BuildHasher;
+use rayon::iter::{FromParallelIterator, IntoParallelIterator, ParallelExtend, ParallelIterator};
+
+/// Interns all strings of a parallel iterator.
+///
+/// # Note
+///
+/// The strings are hashed in parallel and then interned single-threaded using
+/// their precomputed hashes, see [`StringInterner::get_or_intern_hashed`].
+/// The strings are interned in the order of the parallel iterator so the
+/// resulting symbols are the same as for [`Extend`].
+impl<B, H, T> ParallelExtend<T> for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Sync,
+    T: AsRef<str> + Send,
+{
+    fn par_extend<I>(&mut self, par_iter: I)
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let hasher = self.hasher();
+        let hashed = par_iter
+            .into_par_iter()
+            .map(|string| (make_hash(hasher, string.as_ref()), string))
+            .collect::<Vec<_>>();
+        let _ = self.try_reserve(hashed.len());
+        for (hash, string) in hashed {
+            self.get_or_intern_hashed(string, hash);
+        }
+    }
+}
+
+/// Creates a [`StringInterner`] from the strings of a parallel iterator.
+///
+/// See the [`ParallelExtend`] implementation for details.
+impl<B, H, T> FromParallelIterator<T> for StringInterner<B, H>
+where
+    B: Backend,
+    <B as Backend>::Symbol: Symbol,
+    H: BuildHasher + Default + Sync,
+    T: AsRef<str> + Send,
+{
+    fn from_par_iter<I>(par_iter: I) -> Self
+    where
+        I: IntoParallelIterator<Item = T>,
+    {
+        let mut interner = Self::new();
+        interner.par_extend(par_iter);
+        interner
+    }
+}
+
+#[cfg(all(test, feature = "backends"))]
+mod tests {
+    use crate::{DefaultBackend, DefaultStringInterner, StringInterner};
+    use rayon::iter::{
+        IntoParallelIterator, IntoParallelRefIterator, ParallelExtend, ParallelIterator,
+    };
+
+    #[test]
+    fn par_extend_works() {
+        let strings = (0..1000)
+            .map(|i| format!("string-{}", i % 300))
+            .collect::<Vec<_>>();
+        let expected = strings.iter().collect::<DefaultStringInterner>();
+        let mut interner = <DefaultStringInterner>::new();
+        interner.par_extend(strings.par_iter());
+        assert!(interner == expected);
+        let collected = strings
+            .into_par_iter()
+            .collect::<StringInterner<DefaultBackend>>();
+        assert!(collected == expected);
+    }
+}
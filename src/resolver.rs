@@ -0,0 +1,132 @@
+use crate::{backend::Backend, Iter, Symbol};
+use core::{
+    fmt,
+    fmt::{Debug, Formatter},
+};
+use hashbrown::HashSet;
+
+/// Read-only view of the strings of a [`StringInterner`](`crate::StringInterner`).
+///
+/// Can only resolve symbols but no longer intern strings. Obtained via
+/// [`StringInterner::into_resolver`](`crate::StringInterner::into_resolver`).
+///
+/// # Example
+///
+/// ```
+/// # use string_interner::{DefaultStringInterner, Resolver};
+/// let mut interner = <DefaultStringInterner>::new();
+/// let sym = interner.get_or_intern("Banana");
+/// let resolver = interner.into_resolver();
+/// assert_eq!(resolver.resolve(sym), Some("Banana"));
+/// ```
+pub struct Resolver<B>
+where
+    B: Backend,
+{
+    backend: B,
+    /// The `usize` representations of all symbols that have been removed.
+    tombstones: HashSet<usize>,
+    len: usize,
+}
+
+impl<B> Debug for Resolver<B>
+where
+    B: Backend + Debug,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Resolver")
+            .field("backend", &self.backend)
+            .field("tombstones", &self.tombstones)
+            .finish()
+    }
+}
+
+impl<B> Clone for Resolver<B>
+where
+    B: Backend + Clone,
+{
+    fn clone(&self) -> Self {
+        Self {
+            backend: self.backend.clone(),
+            tombstones: self.tombstones.clone(),
+            len: self.len,
+        }
+    }
+}
+
+impl<B> Resolver<B>
+where
+    B: Backend,
+{
+    /// Creates a new resolver from the parts of a string interner.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub(crate) fn new(backend: B, tombstones: HashSet<usize>, len: usize) -> Self {
+        Self {
+            backend,
+            tombstones,
+            len,
+        }
+    }
+
+    /// Returns the number of strings that can be resolved.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// Returns `true` if the resolver has no strings.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    #[inline]
+    pub fn resolve(&self, symbol: <B as Backend>::Symbol) -> Option<&str> {
+        if !self.tombstones.is_empty() && self.tombstones.contains(&symbol.to_usize()) {
+            return None;
+        }
+        self.backend.resolve(symbol)
+    }
+
+    /// Returns the string for the given `symbol` without performing any checks.
+    ///
+    /// # Note
+    ///
+    /// In debug builds `symbol` is validated and an invalid `symbol` causes
+    /// a panic pointing at the caller. Release builds perform no checks.
+    ///
+    /// # Safety
+    ///
+    /// It is the caller's responsibility to provide this method with `symbol`s
+    /// that are valid for the [`Resolver`].
+    #[inline]
+    #[track_caller]
+    pub unsafe fn resolve_unchecked(&self, symbol: <B as Backend>::Symbol) -> &str {
+        debug_assert!(
+            self.resolve(symbol).is_some(),
+            "encountered invalid symbol for resolver"
+        );
+        // SAFETY: The caller guarantees that `symbol` is valid.
+        unsafe { self.backend.resolve_unchecked(symbol) }
+    }
+
+    /// Returns an iterator that yields all strings and their symbols.
+    #[inline]
+    pub fn iter(&self) -> Iter<'_, B> {
+        Iter::new(&self.backend, &self.tombstones, self.len)
+    }
+}
+
+impl<'a, B> IntoIterator for &'a Resolver<B>
+where
+    B: Backend,
+{
+    type Item = (<B as Backend>::Symbol, &'a str);
+    type IntoIter = Iter<'a, B>;
+
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
+}
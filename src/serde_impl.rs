@@ -1,23 +1,42 @@
-use crate::{backend::Backend, StringInterner, Symbol};
+use crate::{
+    backend::Backend,
+    symbol::{RawSymbolU32, SymbolU16, SymbolU32, SymbolU8, SymbolUsize},
+    StringInterner, Symbol,
+};
 #[cfg(not(feature = "std"))]
 use alloc::boxed::Box;
 use core::{default::Default, fmt, hash::BuildHasher, marker};
 use serde::{
-    de::{Deserialize, Deserializer, SeqAccess, Visitor},
-    ser::{Serialize, SerializeSeq, Serializer},
+    de::{Deserialize, Deserializer, Error, SeqAccess, Unexpected, Visitor},
+    ser::{Error as _, Serialize, SerializeSeq, Serializer},
 };
 
+/// Serializes the interned strings as a flat sequence ordered by their symbols.
+///
+/// The symbol of a string is implied by its position in the sequence.
+/// Deserializing re-interns the strings in sequence order which reproduces
+/// the same symbols so that `deserialize(serialize(x)) == x` holds.
+///
+/// # Errors
+///
+/// If the interner contains [removed](`StringInterner::remove`) strings.
+/// Their symbols are never reused so that the symbols of all following
+/// strings could not be reproduced by re-interning.
 impl<B, H> Serialize for StringInterner<B, H>
 where
     B: Backend,
     <B as Backend>::Symbol: Symbol,
-    for<'a> &'a B: IntoIterator<Item = (<B as Backend>::Symbol, &'a str)>,
     H: BuildHasher,
 {
     fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
     where
         T: Serializer,
     {
+        if self.slot_count() != self.len() {
+            return Err(T::Error::custom(
+                "cannot serialize an interner with removed strings",
+            ));
+        }
         let mut seq = serializer.serialize_seq(Some(self.len()))?;
         for (_symbol, string) in self {
             seq.serialize_element(string)?
@@ -26,6 +45,15 @@ where
     }
 }
 
+/// Deserializes an interner from a flat sequence of strings.
+///
+/// Every string is re-interned in sequence order so that the resulting
+/// interner upholds all of its invariants even for untrusted input.
+///
+/// # Errors
+///
+/// - If the sequence contains the same string more than once.
+/// - If the sequence contains more strings than the symbol type can represent.
 impl<'de, B, H> Deserialize<'de> for StringInterner<B, H>
 where
     B: Backend,
@@ -40,6 +68,12 @@ where
     }
 }
 
+/// The maximum number of strings preallocated for when deserializing an interner.
+///
+/// The length of a sequence is untrusted input. Therefore it is only used as
+/// a cautious capacity hint and the interner grows beyond it while interning.
+const MAX_PREALLOCATED_STRINGS: usize = 4096;
+
 struct StringInternerVisitor<B, H>
 where
     B: Backend,
@@ -71,18 +105,209 @@ where
     type Value = StringInterner<B, H>;
 
     fn expecting(&self, formatter: &mut fmt::Formatter) -> fmt::Result {
-        formatter.write_str("Expected a contiguous sequence of strings.")
+        formatter.write_str("a sequence of unique strings")
     }
 
     fn visit_seq<A>(self, mut seq: A) -> Result<Self::Value, A::Error>
     where
         A: SeqAccess<'de>,
     {
+        let capacity = seq.size_hint().unwrap_or(0).min(MAX_PREALLOCATED_STRINGS);
         let mut interner: StringInterner<B, H> =
-            StringInterner::with_capacity_and_hasher(seq.size_hint().unwrap_or(0), H::default());
+            StringInterner::with_capacity_and_hasher(capacity, H::default());
         while let Some(s) = seq.next_element::<Box<str>>()? {
-            interner.get_or_intern(s);
+            let len = interner.len();
+            interner.try_get_or_intern(&*s).map_err(A::Error::custom)?;
+            if interner.len() == len {
+                return Err(A::Error::custom(format_args!("duplicate string {:?}", s)));
+            }
         }
         Ok(interner)
     }
 }
+
+macro_rules! impl_serde_for_symbol {
+    ( $( $name:ident($base_ty:ty) ),* $(,)? ) => {
+        $(
+            /// Serializes the symbol as its raw non-zero integer representation.
+            ///
+            #[doc = concat!("This is the value returned by [`", stringify!($name), "::to_raw`]")]
+            /// which is the index of the symbol plus one, not the value
+            /// returned by [`Symbol::to_usize`].
+            impl Serialize for $name {
+                fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+                where
+                    T: Serializer,
+                {
+                    self.to_raw().serialize(serializer)
+                }
+            }
+
+            /// Deserializes the symbol from its raw non-zero integer representation.
+            ///
+            /// # Errors
+            ///
+            /// If the deserialized integer is zero.
+            impl<'de> Deserialize<'de> for $name {
+                fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+                where
+                    D: Deserializer<'de>,
+                {
+                    let raw = <$base_ty>::deserialize(deserializer)?;
+                    Self::from_raw(raw).ok_or_else(|| {
+                        D::Error::invalid_value(Unexpected::Unsigned(0), &"a non-zero integer")
+                    })
+                }
+            }
+        )*
+    };
+}
+impl_serde_for_symbol!(
+    SymbolU8(u8),
+    SymbolU16(u16),
+    SymbolU32(u32),
+    SymbolUsize(usize)
+);
+
+/// Serializes the symbol as its raw zero-based integer representation.
+impl Serialize for RawSymbolU32 {
+    fn serialize<T>(&self, serializer: T) -> Result<T::Ok, T::Error>
+    where
+        T: Serializer,
+    {
+        self.to_raw().serialize(serializer)
+    }
+}
+
+/// Deserializes the symbol from its raw zero-based integer representation.
+impl<'de> Deserialize<'de> for RawSymbolU32 {
+    fn deserialize<D>(deserializer: D) -> Result<Self, D::Error>
+    where
+        D: Deserializer<'de>,
+    {
+        u32::deserialize(deserializer).map(Self::from_raw)
+    }
+}
+
+#[cfg(all(test, feature = "backends"))]
+mod tests {
+    use crate::{
+        backend::{Backend, BucketBackend, BufferBackend, StringBackend, SubstringBackend},
+        symbol::{RawSymbolU32, SymbolU16, SymbolU32, SymbolU8, SymbolUsize},
+        StringInterner, Symbol,
+    };
+
+    fn assert_round_trip<B>()
+    where
+        B: Backend,
+        StringInterner<B>: PartialEq,
+    {
+        let interner = ["b", "a", "", "c", "a"]
+            .into_iter()
+            .collect::<StringInterner<B>>();
+        let json = serde_json::to_string(&interner).unwrap();
+        assert_eq!(json, r#"["b","a","","c"]"#);
+        let deserialized = serde_json::from_str::<StringInterner<B>>(&json).unwrap();
+        assert!(deserialized == interner);
+        for (symbol, string) in &interner {
+            assert_eq!(
+                deserialized.get(string).map(Symbol::to_usize),
+                Some(symbol.to_usize())
+            );
+        }
+    }
+
+    #[test]
+    fn deserialize_bogus_length_works() {
+        use super::StringInternerVisitor;
+        use serde::de::{
+            value::{Error, StrDeserializer},
+            DeserializeSeed, IntoDeserializer, SeqAccess, Visitor,
+        };
+
+        /// Sequence that claims to be far longer than it actually is.
+        struct BogusSeq(core::slice::Iter<'static, &'static str>);
+
+        impl<'de> SeqAccess<'de> for BogusSeq {
+            type Error = Error;
+
+            fn next_element_seed<T>(&mut self, seed: T) -> Result<Option<T::Value>, Error>
+            where
+                T: DeserializeSeed<'de>,
+            {
+                self.0
+                    .next()
+                    .map(|&string| {
+                        let deserializer: StrDeserializer<Error> = string.into_deserializer();
+                        seed.deserialize(deserializer)
+                    })
+                    .transpose()
+            }
+
+            fn size_hint(&self) -> Option<usize> {
+                Some(usize::MAX)
+            }
+        }
+
+        const STRINGS: &[&str] = &["a", "b"];
+        let interner = StringInternerVisitor::<StringBackend, crate::DefaultHashBuilder>::default()
+            .visit_seq(BogusSeq(STRINGS.iter()))
+            .unwrap();
+        assert_eq!(interner.len(), 2);
+        assert!(interner.hashmap_capacity() <= super::MAX_PREALLOCATED_STRINGS * 2);
+    }
+
+    #[test]
+    fn deserialize_duplicate_fails() {
+        let result = serde_json::from_str::<StringInterner<StringBackend>>(r#"["a","b","a"]"#);
+        assert!(result.is_err());
+    }
+
+    #[test]
+    fn serialize_removed_fails() {
+        let mut interner = ["a", "b", "c"]
+            .into_iter()
+            .collect::<StringInterner<StringBackend>>();
+        let c = interner.get("c").unwrap();
+        let b = interner.get("b").unwrap();
+        interner.remove(b);
+        assert!(serde_json::to_string(&interner).is_err());
+        // Deserializing the remaining strings would shift the symbol of `"c"`.
+        let json = serde_json::to_string(&["a", "c"]).unwrap();
+        let deserialized = serde_json::from_str::<StringInterner<StringBackend>>(&json).unwrap();
+        assert_ne!(
+            deserialized.get("c").map(Symbol::to_usize),
+            Some(c.to_usize())
+        );
+    }
+
+    #[test]
+    fn symbol_round_trip_works() {
+        fn check<S>()
+        where
+            S: Symbol + core::fmt::Debug + serde::Serialize + serde::de::DeserializeOwned,
+        {
+            let symbol = S::from_usize(41);
+            let json = serde_json::to_string(&symbol).unwrap();
+            assert_eq!(json, "42");
+            assert_eq!(serde_json::from_str::<S>(&json).unwrap(), symbol);
+            assert!(serde_json::from_str::<S>("0").is_err());
+        }
+        check::<SymbolU8>();
+        check::<SymbolU16>();
+        check::<SymbolU32>();
+        check::<SymbolUsize>();
+        let symbol = RawSymbolU32::from_usize(41);
+        assert_eq!(serde_json::to_string(&symbol).unwrap(), "41");
+        let zero = serde_json::from_str::<RawSymbolU32>("0").unwrap();
+        assert_eq!(zero.to_usize(), 0);
+    }
+
+    #[test]
+    fn round_trip_works() {
+        assert_round_trip::<StringBackend>();
+        assert_round_trip::<BufferBackend>();
+        assert_round_trip::<BucketBackend>();
+        assert_round_trip::<SubstringBackend>();
+    }
+}
@@ -0,0 +1,352 @@
+#![cfg(feature = "sync")]
+
+use crate::{dedup::make_hash, DefaultSymbol, Symbol};
+use core::{
+    cell::UnsafeCell,
+    fmt,
+    fmt::{Debug, Formatter},
+    hash::BuildHasher,
+    mem::MaybeUninit,
+    ptr,
+    sync::atomic::{AtomicPtr, AtomicUsize, Ordering},
+};
+use hashbrown::{
+    hash_map::{DefaultHashBuilder, RawEntryMut},
+    HashMap,
+};
+use std::sync::{Mutex, PoisonError};
+
+/// The base-2 logarithm of the number of slots of the first bucket.
+const FIRST_BUCKET_LEN_LOG2: u32 = 5;
+/// The number of slots of the first bucket.
+const FIRST_BUCKET_LEN: usize = 1 << FIRST_BUCKET_LEN_LOG2;
+/// The number of buckets required to address all `usize` indices.
+const NUM_BUCKETS: usize = (usize::BITS - FIRST_BUCKET_LEN_LOG2) as usize;
+
+/// A slot holding an interned string once it has been published.
+type Slot = UnsafeCell<MaybeUninit<Box<str>>>;
+
+/// Returns the number of slots of the bucket at index `bucket`.
+#[inline]
+fn bucket_len(bucket: usize) -> usize {
+    FIRST_BUCKET_LEN << bucket
+}
+
+/// Returns the bucket and the offset within the bucket for the given `index`.
+#[inline]
+fn location(index: usize) -> (usize, usize) {
+    let pos = index + FIRST_BUCKET_LEN;
+    let bucket = (usize::BITS - 1 - pos.leading_zeros() - FIRST_BUCKET_LEN_LOG2) as usize;
+    (bucket, pos - bucket_len(bucket))
+}
+
+/// Data structure to intern and resolve strings that can be shared between threads.
+///
+/// Unlike the [`StringInterner`](`crate::StringInterner`) all operations take `&self`.
+/// Interning is synchronized by a lock whereas resolution of symbols is lock-free.
+///
+/// # Note
+///
+/// Interned strings are stored in append-only buckets that are never reallocated.
+/// Therefore resolved strings stay valid for as long as the interner is alive.
+///
+/// # Example
+///
+/// ```
+/// use string_interner::SharedInterner;
+/// use std::{sync::Arc, thread};
+///
+/// let interner = Arc::new(<SharedInterner>::new());
+/// let handles = (0..4)
+///     .map(|_| {
+///         let interner = Arc::clone(&interner);
+///         thread::spawn(move || interner.get_or_intern("Tiger"))
+///     })
+///     .collect::<Vec<_>>();
+/// let symbols = handles.into_iter().map(|handle| handle.join().unwrap());
+/// for symbol in symbols {
+///     assert_eq!(interner.resolve(symbol), Some("Tiger"));
+/// }
+/// assert_eq!(interner.len(), 1);
+/// ```
+pub struct SharedInterner<S = DefaultSymbol, H = DefaultHashBuilder> {
+    dedup: Mutex<HashMap<S, (), ()>>,
+    hasher: H,
+    buckets: [AtomicPtr<Slot>; NUM_BUCKETS],
+    len: AtomicUsize,
+}
+
+/// # Safety
+///
+/// The shared interner owns all of its interned strings and buckets.
+unsafe impl<S, H> Send for SharedInterner<S, H>
+where
+    S: Send,
+    H: Send,
+{
+}
+
+/// # Safety
+///
+/// Slots are only written while holding the `dedup` lock and before they are
+/// published by a release store to `len`. Readers only access slots below an
+/// acquired `len` which are never written to again.
+unsafe impl<S, H> Sync for SharedInterner<S, H>
+where
+    S: Send,
+    H: Sync,
+{
+}
+
+impl<S, H> Debug for SharedInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.debug_struct("SharedInterner")
+            .field("len", &self.len())
+            .finish_non_exhaustive()
+    }
+}
+
+impl<S, H> Default for SharedInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    #[cfg_attr(feature = "inline-more", inline)]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<S, H> Drop for SharedInterner<S, H> {
+    fn drop(&mut self) {
+        let len = *self.len.get_mut();
+        for index in 0..len {
+            let (bucket, offset) = location(index);
+            let slots = *self.buckets[bucket].get_mut();
+            // SAFETY: All slots below `len` have been initialized.
+            unsafe { (*(*slots.add(offset)).get()).assume_init_drop() }
+        }
+        for (bucket, slots) in self.buckets.iter_mut().enumerate() {
+            let slots = *slots.get_mut();
+            if !slots.is_null() {
+                let slots = ptr::slice_from_raw_parts_mut(slots, bucket_len(bucket));
+                // SAFETY: Non-null buckets have been allocated by `push` with `bucket_len` slots.
+                drop(unsafe { Box::from_raw(slots) });
+            }
+        }
+    }
+}
+
+impl<S, H> SharedInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher + Default,
+{
+    /// Creates a new empty `SharedInterner`.
+    #[cfg_attr(feature = "inline-more", inline)]
+    pub fn new() -> Self {
+        Self::with_hasher(Default::default())
+    }
+}
+
+impl<S, H> SharedInterner<S, H>
+where
+    S: Symbol,
+    H: BuildHasher,
+{
+    /// Creates a new empty `SharedInterner` with the given hasher.
+    pub fn with_hasher(hash_builder: H) -> Self {
+        Self {
+            dedup: Mutex::new(HashMap::default()),
+            hasher: hash_builder,
+            buckets: core::array::from_fn(|_| AtomicPtr::new(ptr::null_mut())),
+            len: AtomicUsize::new(0),
+        }
+    }
+
+    /// Returns the number of strings interned by the interner.
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len.load(Ordering::Acquire)
+    }
+
+    /// Returns `true` if the string interner has no interned strings.
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// Returns the string stored at `index`.
+    ///
+    /// # Safety
+    ///
+    /// The caller has to make sure that `index` is below an acquired `len`
+    /// or that the `dedup` lock is held and `index` is below `len`.
+    #[inline]
+    unsafe fn resolve_index_unchecked(&self, index: usize) -> &str {
+        let (bucket, offset) = location(index);
+        let slots = self.buckets[bucket].load(Ordering::Acquire);
+        // SAFETY: The caller guarantees that the slot has been initialized
+        //         and published which also implies that its bucket exists.
+        unsafe { (*(*slots.add(offset)).get()).assume_init_ref() }
+    }
+
+    /// Pushes the given string into the next free slot and publishes it.
+    ///
+    /// # Safety
+    ///
+    /// The caller has to hold the `dedup` lock.
+    ///
+    /// # Panics
+    ///
+    /// If the interner ran out of symbols.
+    unsafe fn push(&self, string: &str) -> S {
+        let index = self.len.load(Ordering::Relaxed);
+        let symbol = S::from_usize(index);
+        let (bucket, offset) = location(index);
+        let mut slots = self.buckets[bucket].load(Ordering::Acquire);
+        if slots.is_null() {
+            let new_slots: Box<[Slot]> = (0..bucket_len(bucket))
+                .map(|_| UnsafeCell::new(MaybeUninit::uninit()))
+                .collect();
+            slots = Box::into_raw(new_slots).cast::<Slot>();
+            self.buckets[bucket].store(slots, Ordering::Release);
+        }
+        // SAFETY: The slot is not yet published so no reader can observe it
+        //         and the caller holds the lock so no other writer exists.
+        unsafe {
+            (*slots.add(offset))
+                .get()
+                .write(MaybeUninit::new(Box::from(string)))
+        };
+        self.len.store(index + 1, Ordering::Release);
+        symbol
+    }
+
+    /// Returns the symbol for the given string if any.
+    ///
+    /// # Note
+    ///
+    /// This acquires the interning lock.
+    pub fn get<T>(&self, string: T) -> Option<S>
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let hash = make_hash(&self.hasher, string);
+        let dedup = self.dedup.lock().unwrap_or_else(PoisonError::into_inner);
+        dedup
+            .raw_entry()
+            .from_hash(hash, |symbol| {
+                // SAFETY: We hold the lock and all symbols in `dedup` are published.
+                string == unsafe { self.resolve_index_unchecked(symbol.to_usize()) }
+            })
+            .map(|(&symbol, &())| symbol)
+    }
+
+    /// Interns the given string.
+    ///
+    /// Returns a symbol for resolution into the original string.
+    ///
+    /// # Note
+    ///
+    /// This acquires the interning lock.
+    ///
+    /// # Panics
+    ///
+    /// If the interner already interns the maximum number of strings possible
+    /// by the chosen symbol type.
+    pub fn get_or_intern<T>(&self, string: T) -> S
+    where
+        T: AsRef<str>,
+    {
+        let string = string.as_ref();
+        let hash = make_hash(&self.hasher, string);
+        let mut dedup = self.dedup.lock().unwrap_or_else(PoisonError::into_inner);
+        let entry = dedup.raw_entry_mut().from_hash(hash, |symbol| {
+            // SAFETY: We hold the lock and all symbols in `dedup` are published.
+            string == unsafe { self.resolve_index_unchecked(symbol.to_usize()) }
+        });
+        match entry {
+            RawEntryMut::Occupied(occupied) => *occupied.key(),
+            RawEntryMut::Vacant(vacant) => {
+                // SAFETY: We hold the lock.
+                let symbol = unsafe { self.push(string) };
+                vacant.insert_with_hasher(hash, symbol, (), |symbol| {
+                    // SAFETY: We hold the lock and all symbols in `dedup` are published.
+                    let string = unsafe { self.resolve_index_unchecked(symbol.to_usize()) };
+                    make_hash(&self.hasher, string)
+                });
+                symbol
+            }
+        }
+    }
+
+    /// Returns the string for the given `symbol` if any.
+    ///
+    /// # Note
+    ///
+    /// This never acquires the interning lock.
+    #[inline]
+    pub fn resolve(&self, symbol: S) -> Option<&str> {
+        let index = symbol.to_usize();
+        if index >= self.len() {
+            return None;
+        }
+        // SAFETY: We just checked that `index` is below an acquired `len`.
+        Some(unsafe { self.resolve_index_unchecked(index) })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::{format, string::String, sync::Arc, thread, vec::Vec};
+
+    #[test]
+    fn location_works() {
+        assert_eq!(location(0), (0, 0));
+        assert_eq!(location(FIRST_BUCKET_LEN - 1), (0, FIRST_BUCKET_LEN - 1));
+        assert_eq!(location(FIRST_BUCKET_LEN), (1, 0));
+        assert_eq!(
+            location(3 * FIRST_BUCKET_LEN - 1),
+            (1, 2 * FIRST_BUCKET_LEN - 1)
+        );
+        assert_eq!(location(3 * FIRST_BUCKET_LEN), (2, 0));
+    }
+
+    #[test]
+    fn concurrent_intern_and_resolve_works() {
+        let interner = Arc::new(<SharedInterner>::new());
+        let strings = (0..1000).map(|i| format!("{i}")).collect::<Arc<[String]>>();
+        let handles = (0..4)
+            .map(|_| {
+                let interner = Arc::clone(&interner);
+                let strings = Arc::clone(&strings);
+                thread::spawn(move || {
+                    strings
+                        .iter()
+                        .map(|string| {
+                            let symbol = interner.get_or_intern(string);
+                            assert_eq!(interner.resolve(symbol), Some(string.as_str()));
+                            symbol
+                        })
+                        .collect::<Vec<_>>()
+                })
+            })
+            .collect::<Vec<_>>();
+        let results = handles
+            .into_iter()
+            .map(|handle| handle.join().unwrap())
+            .collect::<Vec<_>>();
+        assert!(results.windows(2).all(|pair| pair[0] == pair[1]));
+        assert_eq!(interner.len(), strings.len());
+        for (string, &symbol) in strings.iter().zip(&results[0]) {
+            assert_eq!(interner.get(string), Some(symbol));
+        }
+    }
+}
@@ -0,0 +1,32 @@
+#![cfg(feature = "stats")]
+
+/// Deduplication statistics of a [`StringInterner`](`crate::StringInterner`).
+///
+/// Returned by [`StringInterner::stats`](`crate::StringInterner::stats`).
+///
+/// # Note
+///
+/// Counts all intern requests since the interner has been created or
+/// [cleared](`crate::StringInterner::clear`) the last time.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub struct InternStats {
+    /// The total number of intern requests.
+    pub total_interned: usize,
+    /// The number of intern requests that created a new string.
+    pub unique: usize,
+    /// The summed up lengths of all intern requests that hit an existing string.
+    pub bytes_saved: usize,
+}
+
+impl InternStats {
+    /// Records an intern request of a string with `len` bytes.
+    #[inline]
+    pub(crate) fn record(&mut self, len: usize, is_new: bool) {
+        self.total_interned += 1;
+        if is_new {
+            self.unique += 1;
+        } else {
+            self.bytes_saved += len;
+        }
+    }
+}
@@ -5,9 +5,14 @@
 //! method returns `Symbol` types that allow to look-up the original string
 //! using [`StringInterner::resolve`](`crate::StringInterner::resolve`).
 
-use core::num::{NonZeroU16, NonZeroU32, NonZeroUsize};
-
-use std::fmt::Display;
+use core::{
+    cmp::Ordering,
+    fmt,
+    fmt::{Debug, Display},
+    hash::{Hash, Hasher},
+    marker::PhantomData,
+    num::{NonZeroU16, NonZeroU32, NonZeroU8, NonZeroUsize},
+};
 
 /// Types implementing this trait can be used as symbols for string interners.
 ///
@@ -18,28 +23,103 @@ use std::fmt::Display;
 /// # Note
 ///
 /// Optimal symbols allow for efficient comparisons and have a small memory footprint.
+///
+/// # Contract
+///
+/// Interners and backends create and inspect symbols exclusively through this trait.
+/// Implementations must uphold the following invariants for every `n: usize`:
+///
+/// - If `try_from_usize(n)` returns `Some(symbol)` then `symbol.to_usize() == n`.
+/// - If `try_from_usize(n)` returns `None` then `try_from_usize(m)` returns `None`
+///   for all `m > n`, i.e. the representable values form a prefix of `usize`.
+/// - `from_usize(n)` is equivalent to `try_from_usize(n).unwrap()`.
+/// - Two symbols compare equal if and only if their `to_usize` values are equal.
+///
+/// Violating these invariants does not cause undefined behavior but may cause
+/// interners to resolve symbols to the wrong strings or to panic.
+///
+/// # Ordering
+///
+/// Backends assign strictly increasing `to_usize` values to newly interned
+/// strings. The symbol types provided by this crate are ordered by these
+/// values, therefore `a < b` implies that `a` has been interned before `b`.
+/// This allows to use symbols as watermarks, e.g. for
+/// [`StringInterner::symbols_in_range`](`crate::StringInterner::symbols_in_range`).
+///
+/// # Example
+///
+/// A custom symbol that is tagged with a type to prevent mixing symbols of different interners.
+/// The crate ships a ready-made version of this as [`TaggedSymbol`].
+///
+/// ```
+/// # use core::{marker::PhantomData, num::NonZeroU32};
+/// # use string_interner::{backend::StringBackend, StringInterner, Symbol};
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// struct MySymbol<Tag> {
+///     value: NonZeroU32,
+///     tag: PhantomData<fn() -> Tag>,
+/// }
+///
+/// impl<Tag: Copy + Eq> Symbol for MySymbol<Tag> {
+///     fn try_from_usize(index: usize) -> Option<Self> {
+///         let value = u32::try_from(index).ok()?.checked_add(1)?;
+///         NonZeroU32::new(value).map(|value| Self { value, tag: PhantomData })
+///     }
+///
+///     fn to_usize(self) -> usize {
+///         self.value.get() as usize - 1
+///     }
+/// }
+///
+/// #[derive(Debug, Copy, Clone, PartialEq, Eq)]
+/// struct Identifiers;
+/// let mut interner = StringInterner::<StringBackend<MySymbol<Identifiers>>>::new();
+/// let symbol = interner.get_or_intern("main");
+/// assert_eq!(interner.resolve(symbol), Some("main"));
+/// ```
 pub trait Symbol: Copy + Eq {
     /// Creates a symbol from a `usize`.
     ///
     /// Returns `None` if `index` is out of bounds for the symbol.
     fn try_from_usize(index: usize) -> Option<Self>;
 
+    /// Creates a symbol from a `usize`.
+    ///
+    /// This is used by the provided backends whenever they create new symbols.
+    /// Custom symbols may implement this to provide a more efficient conversion.
+    ///
+    /// # Panics
+    ///
+    /// If `index` is out of bounds for the symbol.
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        Self::try_from_usize(index).expect("encountered invalid symbol")
+    }
+
     /// Returns the `usize` representation of `self`.
     fn to_usize(self) -> usize;
 }
 
-/// Creates the symbol `S` from the given `usize`.
+/// Converts the symbol `symbol` of type `A` into the equivalent symbol of type `B`.
+///
+/// Returns `None` if the value of `symbol` is out of bounds for `B`.
 ///
-/// # Panics
+/// # Example
 ///
-/// Panics if the conversion is invalid.
-#[cfg(feature = "backends")]
+/// ```
+/// # use string_interner::symbol::{convert_symbol, Symbol, SymbolU16, SymbolU32};
+/// let small = SymbolU32::try_from_usize(42).unwrap();
+/// let large = SymbolU32::try_from_usize(100_000).unwrap();
+/// assert_eq!(convert_symbol::<_, SymbolU16>(small).map(Symbol::to_usize), Some(42));
+/// assert_eq!(convert_symbol::<_, SymbolU16>(large), None);
+/// ```
 #[inline]
-pub(crate) fn expect_valid_symbol<S>(index: usize) -> S
+pub fn convert_symbol<A, B>(symbol: A) -> Option<B>
 where
-    S: Symbol,
+    A: Symbol,
+    B: Symbol,
 {
-    S::try_from_usize(index).expect("encountered invalid symbol")
+    B::try_from_usize(symbol.to_usize())
 }
 
 /// The symbol type that is used by default.
@@ -63,16 +143,55 @@ macro_rules! gen_symbol_for {
         struct $name:ident($non_zero:ty; $base_ty:ty);
     ) => {
         $( #[$doc] )*
+        ///
+        /// # ABI
+        ///
+        #[doc = concat!(
+            "The symbol is `#[repr(transparent)]` over `", stringify!($non_zero),
+            "` and therefore has the same layout and ABI as `", stringify!($base_ty),
+            "`, e.g. when passed across an FFI boundary."
+        )]
+        #[doc = concat!(
+            "The same holds for `Option<", stringify!($name), ">` where `None` is represented by `0`."
+        )]
+        ///
+        /// Note that the raw value is the index of the symbol plus one,
+        /// see [`to_raw`](Self::to_raw) and [`from_raw`](Self::from_raw).
         #[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        #[repr(transparent)]
         pub struct $name {
             pub value: $non_zero,
         }
 
+        impl $name {
+            #[doc = concat!("Creates a symbol from its raw `", stringify!($base_ty), "` representation.")]
+            ///
+            /// Returns `None` if `raw` is zero.
+            ///
+            /// # Note
+            ///
+            /// The raw representation is the index of the symbol plus one and
+            /// does not depend on the width of `usize` of the target platform.
+            #[inline]
+            pub const fn from_raw(raw: $base_ty) -> Option<Self> {
+                match <$non_zero>::new(raw) {
+                    Some(value) => Some(Self { value }),
+                    None => None,
+                }
+            }
+
+            #[doc = concat!("Returns the raw `", stringify!($base_ty), "` representation of `self`.")]
+            #[inline]
+            pub const fn to_raw(self) -> $base_ty {
+                self.value.get()
+            }
+        }
+
         impl Symbol for $name {
             #[inline]
             fn try_from_usize(index: usize) -> Option<Self> {
-                <$non_zero>::new((index as $base_ty).wrapping_add(1))
-                    .map(|value| Self { value })
+                let value = <$base_ty>::try_from(index).ok()?.checked_add(1)?;
+                <$non_zero>::new(value).map(|value| Self { value })
             }
 
             #[inline]
@@ -82,12 +201,21 @@ macro_rules! gen_symbol_for {
         }
 
         impl Display for $name {
-            fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
                 write!(f, "{}", self.value)
             }
         }
     };
 }
+gen_symbol_for!(
+    /// Symbol that is 8-bit in size.
+    ///
+    /// Can represent at most 255 strings which makes it suitable for
+    /// small and fixed sets of strings.
+    ///
+    /// Is space-optimized for used in `Option`.
+    struct SymbolU8(NonZeroU8; u8);
+);
 gen_symbol_for!(
     /// Symbol that is 16-bit in size.
     ///
@@ -107,6 +235,181 @@ gen_symbol_for!(
     struct SymbolUsize(NonZeroUsize; usize);
 );
 
+/// Symbol that is 32-bit in size and whose value is its zero-based index.
+///
+/// Unlike [`SymbolU32`] the conversion from and to `usize` is the identity
+/// which saves the offset by one on every resolution and allows passing
+/// plain zero-based indices across an FFI boundary.
+///
+/// # Note
+///
+/// Since `0` is a valid value `RawSymbolU32` is **not** space-optimized for
+/// use in `Option`: `Option<RawSymbolU32>` is 8 bytes in size whereas
+/// `Option<SymbolU32>` is 4 bytes. Additionally, `RawSymbolU32` can represent
+/// one more string than [`SymbolU32`], namely up to `u32::MAX + 1` strings.
+///
+/// # ABI
+///
+/// The symbol is `#[repr(transparent)]` over `u32` and therefore has the same
+/// layout and ABI as `u32`. Its raw value is the index of the symbol.
+#[derive(Debug, Copy, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+#[repr(transparent)]
+pub struct RawSymbolU32 {
+    pub value: u32,
+}
+
+impl RawSymbolU32 {
+    /// Creates a symbol from its raw `u32` representation.
+    ///
+    /// # Note
+    ///
+    /// The raw representation is the index of the symbol.
+    #[inline]
+    pub const fn from_raw(raw: u32) -> Self {
+        Self { value: raw }
+    }
+
+    /// Returns the raw `u32` representation of `self`.
+    #[inline]
+    pub const fn to_raw(self) -> u32 {
+        self.value
+    }
+}
+
+impl Symbol for RawSymbolU32 {
+    #[inline]
+    fn try_from_usize(index: usize) -> Option<Self> {
+        u32::try_from(index).ok().map(Self::from_raw)
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.value as usize
+    }
+}
+
+impl Display for RawSymbolU32 {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.value)
+    }
+}
+
+/// Symbol that is tagged with the type `Tag`.
+///
+/// Tagged symbols of different tags are different types. Using a distinct tag
+/// per interner prevents resolving a symbol with the wrong interner at compile time.
+///
+/// # Example
+///
+/// ```compile_fail
+/// # use string_interner::{backend::StringBackend, symbol::TaggedSymbol, StringInterner};
+/// enum Identifiers {}
+/// enum Literals {}
+/// let mut identifiers = StringInterner::<StringBackend<TaggedSymbol<Identifiers>>>::new();
+/// let mut literals = StringInterner::<StringBackend<TaggedSymbol<Literals>>>::new();
+/// let symbol = identifiers.get_or_intern("main");
+/// literals.resolve(symbol); // error: mismatched types
+/// ```
+///
+/// # ABI
+///
+/// The tagged symbol is `#[repr(transparent)]` over the underlying symbol `S`.
+#[repr(transparent)]
+pub struct TaggedSymbol<Tag, S = DefaultSymbol> {
+    symbol: S,
+    tag: PhantomData<fn() -> Tag>,
+}
+
+impl<Tag, S> TaggedSymbol<Tag, S> {
+    /// Tags the given `symbol` with `Tag`.
+    #[inline]
+    pub fn new(symbol: S) -> Self {
+        Self {
+            symbol,
+            tag: PhantomData,
+        }
+    }
+
+    /// Returns the untagged symbol of `self`.
+    #[inline]
+    pub fn into_inner(self) -> S {
+        self.symbol
+    }
+}
+
+impl<Tag, S> Symbol for TaggedSymbol<Tag, S>
+where
+    S: Symbol,
+{
+    #[inline]
+    fn try_from_usize(index: usize) -> Option<Self> {
+        S::try_from_usize(index).map(Self::new)
+    }
+
+    #[inline]
+    fn from_usize(index: usize) -> Self {
+        Self::new(S::from_usize(index))
+    }
+
+    #[inline]
+    fn to_usize(self) -> usize {
+        self.symbol.to_usize()
+    }
+}
+
+// The trait implementations below are written by hand since derives
+// would require `Tag` to implement the derived traits as well.
+
+impl<Tag, S: Debug> Debug for TaggedSymbol<Tag, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_tuple("TaggedSymbol").field(&self.symbol).finish()
+    }
+}
+
+impl<Tag, S: Display> Display for TaggedSymbol<Tag, S> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.symbol.fmt(f)
+    }
+}
+
+impl<Tag, S: Copy> Copy for TaggedSymbol<Tag, S> {}
+
+impl<Tag, S: Clone> Clone for TaggedSymbol<Tag, S> {
+    #[inline]
+    fn clone(&self) -> Self {
+        Self::new(self.symbol.clone())
+    }
+}
+
+impl<Tag, S: PartialEq> PartialEq for TaggedSymbol<Tag, S> {
+    #[inline]
+    fn eq(&self, other: &Self) -> bool {
+        self.symbol == other.symbol
+    }
+}
+
+impl<Tag, S: Eq> Eq for TaggedSymbol<Tag, S> {}
+
+impl<Tag, S: PartialOrd> PartialOrd for TaggedSymbol<Tag, S> {
+    #[inline]
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.symbol.partial_cmp(&other.symbol)
+    }
+}
+
+impl<Tag, S: Ord> Ord for TaggedSymbol<Tag, S> {
+    #[inline]
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.symbol.cmp(&other.symbol)
+    }
+}
+
+impl<Tag, S: Hash> Hash for TaggedSymbol<Tag, S> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.symbol.hash(state)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -125,6 +428,51 @@ mod tests {
         );
     }
 
+    #[test]
+    fn same_layout_as_raw() {
+        use core::mem::align_of;
+        assert_eq!(size_of::<SymbolU16>(), size_of::<u16>());
+        assert_eq!(align_of::<SymbolU16>(), align_of::<u16>());
+        assert_eq!(size_of::<SymbolUsize>(), size_of::<usize>());
+        assert_eq!(align_of::<SymbolUsize>(), align_of::<usize>());
+        assert_eq!(size_of::<Option<SymbolU32>>(), size_of::<u32>());
+        assert_eq!(align_of::<Option<SymbolU32>>(), align_of::<u32>());
+        let symbol = SymbolU32::try_from_usize(41).unwrap();
+        // SAFETY: `SymbolU32` is `#[repr(transparent)]` over `NonZeroU32`.
+        let raw = unsafe { core::mem::transmute::<SymbolU32, u32>(symbol) };
+        assert_eq!(raw, symbol.to_raw());
+        assert_eq!(raw, 42);
+        // SAFETY: `Option<SymbolU32>` has the same layout as `u32` with `0` as `None`.
+        let none = unsafe { core::mem::transmute::<u32, Option<SymbolU32>>(0) };
+        assert_eq!(none, None);
+    }
+
+    #[test]
+    fn raw_symbol_works() {
+        assert_eq!(size_of::<RawSymbolU32>(), size_of::<u32>());
+        assert_eq!(size_of::<Option<RawSymbolU32>>(), 2 * size_of::<u32>());
+        assert_eq!(
+            RawSymbolU32::try_from_usize(0),
+            Some(RawSymbolU32::from_raw(0))
+        );
+        assert_eq!(RawSymbolU32::from_usize(42).to_raw(), 42);
+        assert_eq!(
+            RawSymbolU32::try_from_usize(u32::MAX as usize).map(Symbol::to_usize),
+            Some(u32::MAX as usize)
+        );
+        assert_eq!(RawSymbolU32::try_from_usize(u32::MAX as usize + 1), None);
+    }
+
+    #[test]
+    fn tagged_symbol_works() {
+        enum Tag {}
+        assert_eq!(size_of::<TaggedSymbol<Tag>>(), size_of::<DefaultSymbol>());
+        let symbol = TaggedSymbol::<Tag>::try_from_usize(42).unwrap();
+        assert_eq!(symbol.to_usize(), 42);
+        assert_eq!(symbol.into_inner(), DefaultSymbol::from_usize(42));
+        assert_eq!(symbol, TaggedSymbol::new(DefaultSymbol::from_usize(42)));
+    }
+
     #[test]
     fn try_from_usize_works() {
         assert_eq!(
@@ -163,9 +511,26 @@ mod tests {
                 );
                 assert_eq!(<$name>::try_from_usize(<$base_ty>::MAX as usize), None);
                 assert_eq!(<$name>::try_from_usize(<usize>::MAX), None);
+                // Indices that would wrap around when truncated to the base type.
+                if let Some(wrapping) = (<$base_ty>::MAX as usize).checked_add(1) {
+                    assert_eq!(<$name>::try_from_usize(wrapping), None);
+                }
+                for val in [0, 1, <$base_ty>::MAX as usize - 1] {
+                    assert_eq!(<$name>::from_usize(val).to_usize(), val);
+                }
+                assert_eq!(<$name>::from_raw(0), None);
+                for raw in [1, 2, <$base_ty>::MAX] {
+                    let symbol = <$name>::from_raw(raw).unwrap();
+                    assert_eq!(symbol.to_raw(), raw);
+                    assert_eq!(symbol.to_usize(), raw as usize - 1);
+                }
             }
         };
     }
+    gen_test_for!(
+        try_from_usize_works_for_u8:
+        struct SymbolU8(NonZeroU8; u8);
+    );
     gen_test_for!(
         try_from_usize_works_for_u16:
         struct SymbolU16(NonZeroU16; u16);